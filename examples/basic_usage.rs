@@ -24,7 +24,7 @@ fn main() {
     }
 
     // get threshold value
-    let threshold_value: u8 = cli.get_value(&*threshold.get_name());
+    let threshold_value: u8 = cli.get_value(&threshold.get_name());
     println!("threshold = {}", threshold_value);
     // OR
     let threshold_value: u8 = cli.get_value("threshold");
@@ -1,6 +1,8 @@
 use std::any::Any;
 use std::cell::{Cell, RefCell};
+use std::ffi::OsString;
 use std::fmt;
+use std::path::PathBuf;
 
 /// This enum indicates the expected type of the argument value.
 #[derive(Debug)]
@@ -39,6 +41,10 @@ pub enum Type {
     ReadAsChar,
     /// Value is expected to be `String`.
     ReadAsString,
+    /// Value is expected to be `PathBuf`, kept as given even if not unicode.
+    ReadAsPathBuf,
+    /// Value is expected to be `OsString`, kept as given even if not unicode.
+    ReadAsOsString,
 }
 
 #[derive(Debug)]
@@ -60,6 +66,8 @@ pub(crate) enum PrivateType {
     ReadAsBool(bool),
     ReadAsChar(char),
     ReadAsString(String),
+    ReadAsPathBuf(PathBuf),
+    ReadAsOsString(OsString),
 }
 
 impl From<Type> for PrivateType {
@@ -82,44 +90,70 @@ impl From<Type> for PrivateType {
             Type::ReadAsBool => PrivateType::ReadAsBool(false),
             Type::ReadAsChar => PrivateType::ReadAsChar('0'),
             Type::ReadAsString => PrivateType::ReadAsString(String::new()),
+            Type::ReadAsPathBuf => PrivateType::ReadAsPathBuf(PathBuf::new()),
+            Type::ReadAsOsString => PrivateType::ReadAsOsString(OsString::new()),
         }
     }
 }
 
 /// This structure represents an Argument for the command line
-/// in the form "--arg_name value".
+/// in the form "--arg_name value" or "-a value".
 pub struct Arg {
     name: String,
+    pub(crate) shorts: RefCell<Vec<char>>,
+    pub(crate) aliases: RefCell<Vec<String>>,
     pub(crate) description: RefCell<String>,
     pub(crate) type_read: Option<PrivateType>,
     pub(crate) required: bool,
+    pub(crate) required_unless: RefCell<Vec<String>>,
+    pub(crate) required_if_eq: RefCell<Vec<(String, String)>>,
     pub(crate) has_value: bool,
     pub(crate) value: RefCell<Option<Box<dyn Any>>>,
     pub(crate) found: Cell<bool>,
+    pub(crate) occurrences: Cell<usize>,
+    pub(crate) counting: bool,
+    pub(crate) negatable: Cell<bool>,
+    pub(crate) negated: Cell<bool>,
+    pub(crate) allow_hyphen_values: Cell<bool>,
+    pub(crate) global: Cell<bool>,
+    pub(crate) conflicts: RefCell<Vec<String>>,
+    pub(crate) requirements: RefCell<Vec<(Option<String>, String)>>,
     pub(crate) default_value: Option<Box<dyn Any>>,
+    pub(crate) missing_value: Option<Box<dyn Any>>,
+    pub(crate) index: Option<usize>,
+    pub(crate) variadic: Cell<Option<(usize, Option<usize>)>>,
+    pub(crate) multiple: Cell<bool>,
+    pub(crate) num_values: Cell<Option<(usize, usize)>>,
+    pub(crate) delimiter: Cell<Option<char>>,
+    pub(crate) values: RefCell<Vec<Box<dyn Any>>>,
+    pub(crate) raw_values: RefCell<Vec<String>>,
 }
 
 impl fmt::Display for Arg {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = if self.is_positional() {
+            self.display_name()
+        } else {
+            format!("--{}", self.name)
+        };
         if self.has_value {
             // format value as String
             let borrowed_value = self.value.borrow();
-            let value: String;
-            if borrowed_value.is_some() {
-                value = format!("{}", self.format_value());
+            let value = if borrowed_value.is_some() || self.is_multiple() {
+                self.format_value()
             } else {
-                value = String::from("None");
-            }
+                String::from("None")
+            };
 
             if self.has_default_value() {
                 // format default value as String
                 let default_value = format!("{:?}", self.format_default_value());
-                write!(f, "--{}={} (default: {})", self.name, value, default_value)
+                write!(f, "{}={} (default: {})", name, value, default_value)
             } else {
-                write!(f, "--{}={}", self.name, value)
+                write!(f, "{}={}", name, value)
             }
         } else {
-            write!(f, "--{}", self.name)
+            write!(f, "{}", name)
         }
     }
 }
@@ -130,6 +164,31 @@ impl Arg {
             Some(default) => default,
             None => return Err(format!("No default value found for {}!", self.name)),
         };
+        let value = self.copy_value(default_value)?;
+        self.store_value(value);
+        Ok(())
+    }
+
+    pub(crate) fn accept_missing_value(&self) -> Result<(), String> {
+        let missing_value = match self.missing_value.as_ref() {
+            Some(missing) => missing,
+            None => return Err(format!("No missing value found for {}!", self.name)),
+        };
+        let value = self.copy_value(missing_value)?;
+        self.store_value(value);
+        Ok(())
+    }
+
+    pub(crate) fn store_value(&self, value: Box<dyn Any>) {
+        if self.is_multiple() {
+            self.values.borrow_mut().push(value);
+        } else {
+            self.value.replace(Some(value));
+        }
+    }
+
+    /// Copy `default_value`, which must be of the reading type of the `Arg`.
+    fn copy_value(&self, default_value: &Box<dyn Any>) -> Result<Box<dyn Any>, String> {
         let value: Box<dyn Any> = match self.type_read {
             Some(PrivateType::ReadAsU8(_)) => {
                 let tmp = default_value.downcast_ref::<u8>();
@@ -250,11 +309,23 @@ impl Arg {
                     None => return Err(format!("Error downcasting argument {}", self.name)),
                 }
             }
+            Some(PrivateType::ReadAsPathBuf(_)) => {
+                let tmp = default_value.downcast_ref::<PathBuf>();
+                match tmp {
+                    Some(v) => Box::new(v.clone()),
+                    None => return Err(format!("Error downcasting argument {}", self.name)),
+                }
+            }
+            Some(PrivateType::ReadAsOsString(_)) => {
+                let tmp = default_value.downcast_ref::<OsString>();
+                match tmp {
+                    Some(v) => Box::new(v.clone()),
+                    None => return Err(format!("Error downcasting argument {}", self.name)),
+                }
+            }
             None => return Err(format!("Argument {} must have a value", self.name)),
         };
-        self.value.replace(Some(value));
-
-        Ok(())
+        Ok(value)
     }
 
     fn any_to_string(&self, value: &Box<dyn Any>) -> String {
@@ -327,19 +398,34 @@ impl Arg {
                 Some(v) => format!("{:?}", v),
                 None => String::from("None"),
             },
+            Some(PrivateType::ReadAsPathBuf(_)) => match value.downcast_ref::<PathBuf>() {
+                Some(v) => format!("{:?}", v),
+                None => String::from("None"),
+            },
+            Some(PrivateType::ReadAsOsString(_)) => match value.downcast_ref::<OsString>() {
+                Some(v) => format!("{:?}", v),
+                None => String::from("None"),
+            },
             None => String::new(),
         }
     }
 
     pub(crate) fn format_value(&self) -> String {
-        if self.has_value {
+        if self.is_multiple() {
+            let values: Vec<String> = self
+                .values
+                .borrow()
+                .iter()
+                .map(|value| self.any_to_string(value))
+                .collect();
+            format!("[{}]", values.join(", "))
+        } else if self.has_value {
             let borrowed_value = self.value.borrow();
 
             if borrowed_value.is_some() {
-                let value = borrowed_value.as_ref().expect(&*format!(
-                    "Error unwrapping value for argument {}",
-                    self.name
-                ));
+                let value = borrowed_value.as_ref().unwrap_or_else(|| {
+                    panic!("Error unwrapping value for argument {}", self.name)
+                });
                 self.any_to_string(value)
             } else {
                 String::new()
@@ -372,6 +458,393 @@ impl Arg {
         String::from(&self.name)
     }
 
+    /// Get the short names of the `Arg`.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// let arg = Arg::with_value("foo", Type::ReadAsI32, false);
+    /// arg.add_short('f');
+    /// assert_eq!(arg.get_shorts(), vec!['f']);
+    /// ```
+    pub fn get_shorts(&self) -> Vec<char> {
+        self.shorts.borrow().clone()
+    }
+
+    /// Add a single-character short name to the `Arg`, so that it
+    /// also matches "-c value".
+    ///
+    /// # Arguments
+    /// * `short` - The short name of the argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match the optional String argument --config <value> or -c <value>
+    /// let arg = Arg::with_value("config", Type::ReadAsString, false);
+    /// arg.add_short('c');
+    /// ```
+    pub fn add_short(&self, short: char) {
+        let mut shorts = self.shorts.borrow_mut();
+        if !shorts.contains(&short) {
+            shorts.push(short);
+        }
+    }
+
+    /// Get the long aliases of the `Arg`.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// let arg = Arg::with_value("out", Type::ReadAsString, false);
+    /// arg.add_alias("output");
+    /// assert_eq!(arg.get_aliases(), vec![String::from("output")]);
+    /// ```
+    pub fn get_aliases(&self) -> Vec<String> {
+        self.aliases.borrow().clone()
+    }
+
+    /// Add a long alias to the `Arg`, so that it also matches
+    /// "--alias value". The value stays under the name of the `Arg`.
+    ///
+    /// # Arguments
+    /// * `alias` - The other long name of the argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match --out <value>, --output <value> or --output-file <value>
+    /// let arg = Arg::with_value("out", Type::ReadAsString, false);
+    /// arg.add_alias("output");
+    /// arg.add_alias("output-file");
+    /// ```
+    pub fn add_alias(&self, alias: &str) {
+        let mut aliases = self.aliases.borrow_mut();
+        if !aliases.iter().any(|known| known == alias) {
+            aliases.push(String::from(alias));
+        }
+    }
+
+    pub(crate) fn has_short(&self, short: char) -> bool {
+        self.shorts.borrow().contains(&short)
+    }
+
+    /// Name used in messages, e.g. "-c/--config".
+    pub(crate) fn display_name(&self) -> String {
+        self.display_name_with("--", "-")
+    }
+
+    /// Name used in messages with the given prefixes.
+    pub(crate) fn display_name_with(&self, long_prefix: &str, short_prefix: &str) -> String {
+        if self.is_positional() {
+            let dots = if self.is_multiple() { "..." } else { "" };
+            return format!("<{}{}>", self.name.to_uppercase(), dots);
+        }
+        let mut display = String::new();
+        for short in self.shorts.borrow().iter() {
+            display = format!("{}{}{}/", display, short_prefix, short);
+        }
+        format!("{}{}{}", display, long_prefix, self.name)
+    }
+
+    /// Make a positional `Arg` collect every remaining positional value,
+    /// read them with `CliArguments::get_values`.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum count of values.
+    /// * `max` - The maximum count of values, `None` for no limit.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "mytool <input> [output...]"
+    /// let input = Arg::positional("input", 0, Type::ReadAsString, true);
+    /// let output = Arg::positional("output", 1, Type::ReadAsString, false);
+    /// output.set_variadic(0, None);
+    /// ```
+    pub fn set_variadic(&self, min: usize, max: Option<usize>) {
+        self.variadic.set(Some((min, max)));
+    }
+
+    pub(crate) fn has_optional_value(&self) -> bool {
+        self.missing_value.is_some()
+    }
+
+    pub(crate) fn is_positional(&self) -> bool {
+        self.index.is_some()
+    }
+
+    /// Make the `Arg` collect the value of every occurrence instead of
+    /// keeping the last one, read them with `CliArguments::get_values`.
+    ///
+    /// # Arguments
+    /// * `multiple` - Whether or not every value is kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--include a --include b"
+    /// let arg = Arg::with_value("include", Type::ReadAsString, false);
+    /// arg.set_multiple(true);
+    /// ```
+    pub fn set_multiple(&self, multiple: bool) {
+        self.multiple.set(multiple);
+    }
+
+    /// Split each value of the `Arg` on `delimiter`, so that "--include a,b"
+    /// gives two values. This implies `set_multiple(true)`.
+    ///
+    /// # Arguments
+    /// * `delimiter` - The character separating the values.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--include a,b --include c"
+    /// let arg = Arg::with_value("include", Type::ReadAsString, false);
+    /// arg.set_delimiter(',');
+    /// ```
+    pub fn set_delimiter(&self, delimiter: char) {
+        self.multiple.set(true);
+        self.delimiter.set(Some(delimiter));
+    }
+
+    /// Make an `Arg` without value also match "--no-<name>", the last
+    /// occurrence winning. Read the state with `CliArguments::get_toggle`,
+    /// `CliArguments::exists` being `false` once negated.
+    /// "--no-<name>" also resets the count of `CliArguments::get_count`, so
+    /// "-vv --no-verbose -v" counts one occurrence.
+    ///
+    /// # Arguments
+    /// * `negatable` - Whether or not "--no-<name>" is accepted.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--color" and "--no-color"
+    /// let arg = Arg::without_value("color", false);
+    /// arg.set_negatable(true);
+    /// ```
+    pub fn set_negatable(&self, negatable: bool) {
+        self.negatable.set(negatable);
+    }
+
+    /// Make a named `Arg` taking a value read between `min` and `max`
+    /// tokens at each occurrence, like "--range 10 20". Read them with
+    /// `CliArguments::get_values` or `CliArguments::get_array`.
+    /// A default value being one value, parsing fails when `min` is more
+    /// than one and the `Arg` has a default value.
+    ///
+    /// # Arguments
+    /// * `min` - The minimum count of values.
+    /// * `max` - The maximum count of values.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--point <x> <y>"
+    /// let arg = Arg::with_value("point", Type::ReadAsF64, false);
+    /// arg.set_num_values(2, 2);
+    /// ```
+    pub fn set_num_values(&self, min: usize, max: usize) {
+        self.num_values.set(Some((min, max.max(min))));
+    }
+
+    /// Let the values of the `Arg` start with '-', so that "--pattern -x"
+    /// reads "-x" as the value of "--pattern" rather than as an argument.
+    /// This also covers the long prefixes of the `PrefixStyle`, like "/x"
+    /// in the slash style.
+    /// Values of signed numeric `Type`s, like "--offset -5", are always
+    /// accepted when they look like a number.
+    /// Any other token starting with '-' is read as an argument, an
+    /// unknown one being reported as such, except in the styles without
+    /// short clusters where a token naming no known argument is a value.
+    ///
+    /// # Arguments
+    /// * `allow_hyphen_values` - Whether or not values may start with '-'.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--pattern -x"
+    /// let arg = Arg::with_value("pattern", Type::ReadAsString, false);
+    /// arg.set_allow_hyphen_values(true);
+    /// ```
+    pub fn set_allow_hyphen_values(&self, allow_hyphen_values: bool) {
+        self.allow_hyphen_values.set(allow_hyphen_values);
+    }
+
+    /// Declare that the `Arg` cannot be given together with the `Arg`
+    /// named `name`, like "--quiet" and "--verbose".
+    ///
+    /// # Arguments
+    /// * `name` - The name of the conflicting argument, parsing panics
+    ///   if there is no such argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // reject "--quiet --verbose"
+    /// let arg = Arg::without_value("quiet", false);
+    /// arg.add_conflict("verbose");
+    /// ```
+    pub fn add_conflict(&self, name: &str) {
+        let mut conflicts = self.conflicts.borrow_mut();
+        if !conflicts.iter().any(|known| known == name) {
+            conflicts.push(String::from(name));
+        }
+    }
+
+    /// Make the `Arg` required unless the `Arg` named `name` is given,
+    /// like "--output" unless "--stdout". With several calls, the `Arg`
+    /// is required unless any of them is given.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the argument making this one optional,
+    ///   parsing panics if there is no such argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "--output <value>" or "--stdout"
+    /// let arg = Arg::with_value("output", Type::ReadAsString, false);
+    /// arg.add_required_unless("stdout");
+    /// ```
+    pub fn add_required_unless(&self, name: &str) {
+        let mut required_unless = self.required_unless.borrow_mut();
+        if !required_unless.iter().any(|known| known == name) {
+            required_unless.push(String::from(name));
+        }
+    }
+
+    /// Make the `Arg` required when the `Arg` named `name` is given with
+    /// the value `value`, like "--password" when "--auth=basic".
+    /// The value is compared as given on the command line.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the argument making this one required,
+    ///   parsing panics if there is no such argument.
+    /// * `value` - The value of that argument making this one required.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // "--auth basic" needs "--password <value>"
+    /// let arg = Arg::with_value("password", Type::ReadAsString, false);
+    /// arg.add_required_if_eq("auth", "basic");
+    /// ```
+    pub fn add_required_if_eq(&self, name: &str, value: &str) {
+        self.required_if_eq
+            .borrow_mut()
+            .push((String::from(name), String::from(value)));
+    }
+
+    /// Declare that the `Arg` can only be given together with the `Arg`
+    /// named `name`, like "--key" needing "--cert".
+    ///
+    /// # Arguments
+    /// * `name` - The name of the required argument, parsing panics if
+    ///   there is no such argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // reject "--key <value>" without "--cert <value>"
+    /// let arg = Arg::with_value("key", Type::ReadAsString, false);
+    /// arg.add_requirement("cert");
+    /// ```
+    pub fn add_requirement(&self, name: &str) {
+        self.requirements.borrow_mut().push((None, String::from(name)));
+    }
+
+    /// Declare that the `Arg` given with the value `value` needs the `Arg`
+    /// named `name`, like "--tls-mode=mutual" needing "--ca-file".
+    /// The value is compared as given on the command line.
+    ///
+    /// # Arguments
+    /// * `value` - The value of the argument triggering the requirement.
+    /// * `name` - The name of the required argument, parsing panics if
+    ///   there is no such argument.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // reject "--tls-mode mutual" without "--ca-file <value>"
+    /// let arg = Arg::with_value("tls-mode", Type::ReadAsString, false);
+    /// arg.add_requirement_if("mutual", "ca-file");
+    /// ```
+    pub fn add_requirement_if(&self, value: &str, name: &str) {
+        self.requirements
+            .borrow_mut()
+            .push((Some(String::from(value)), String::from(name)));
+    }
+
+    /// Make a named `Arg` global: it is also accepted by every subcommand,
+    /// after the subcommand name, like "app build --verbose".
+    ///
+    /// The value is shared by all the levels, so it can be read from the
+    /// parent as well as from any subcommand. When given at several levels,
+    /// occurrences add up in command line order, as if given at one level:
+    /// the last value wins, while counts and multiple values accumulate.
+    /// An `Arg` of a subcommand takes precedence over a global one with the
+    /// same name.
+    ///
+    /// # Arguments
+    /// * `global` - Whether or not subcommands accept the `Arg`.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "app --verbose build" and "app build --verbose"
+    /// let arg = Arg::without_value("verbose", false);
+    /// arg.set_global(true);
+    /// ```
+    pub fn set_global(&self, global: bool) {
+        self.global.set(global);
+    }
+
+    /// Whether or not a token looking like an argument, starting with '-'
+    /// or a long prefix, is a value of the `Arg`.
+    pub(crate) fn accepts_hyphen_value(&self, token: &str) -> bool {
+        if self.allow_hyphen_values.get() {
+            return true;
+        }
+        // a number of the type of the `Arg`, so "-1e3" is no i32
+        match self.type_read {
+            Some(PrivateType::ReadAsI8(_)) => token.parse::<i8>().is_ok(),
+            Some(PrivateType::ReadAsI16(_)) => token.parse::<i16>().is_ok(),
+            Some(PrivateType::ReadAsI32(_)) => token.parse::<i32>().is_ok(),
+            Some(PrivateType::ReadAsI64(_)) => token.parse::<i64>().is_ok(),
+            Some(PrivateType::ReadAsI128(_)) => token.parse::<i128>().is_ok(),
+            Some(PrivateType::ReadAsIsize(_)) => token.parse::<isize>().is_ok(),
+            Some(PrivateType::ReadAsF32(_)) => token.parse::<f32>().is_ok(),
+            Some(PrivateType::ReadAsF64(_)) => token.parse::<f64>().is_ok(),
+            _ => false,
+        }
+    }
+
+    pub(crate) fn is_multiple(&self) -> bool {
+        self.multiple.get() || self.variadic.get().is_some() || self.num_values.get().is_some()
+    }
+
+    /// Whether or not a value has been read for the argument.
+    pub(crate) fn has_read_value(&self) -> bool {
+        if self.is_multiple() {
+            !self.values.borrow().is_empty()
+        } else {
+            self.value.borrow().is_some()
+        }
+    }
+
+    /// Whether or not the argument may still take values.
+    pub(crate) fn accepts_more_values(&self) -> bool {
+        match self.variadic.get() {
+            Some((_, Some(max))) => self.values.borrow().len() < max,
+            Some((_, None)) => true,
+            None => self.multiple.get() || self.value.borrow().is_none(),
+        }
+    }
+
     pub(crate) fn has_default_value(&self) -> bool {
         self.default_value.is_some()
     }
@@ -415,14 +888,8 @@ impl Arg {
         required: bool,
     ) -> Arg {
         Arg {
-            name: name.to_string(),
-            description: RefCell::new(String::new()),
-            type_read: Some(PrivateType::from(reading_type)),
-            required,
-            has_value: true,
-            value: RefCell::new(None),
-            found: Cell::new(false),
             default_value: Some(default_value),
+            ..Arg::new(name, Some(PrivateType::from(reading_type)), required)
         }
     }
 
@@ -440,16 +907,7 @@ impl Arg {
     /// let arg = Arg::with_value("foo", Type::ReadAsI32, false);
     /// ```
     pub fn with_value(name: &str, reading_type: Type, required: bool) -> Arg {
-        Arg {
-            name: name.to_string(),
-            description: RefCell::new(String::new()),
-            type_read: Some(PrivateType::from(reading_type)),
-            required,
-            has_value: true,
-            value: RefCell::new(None),
-            found: Cell::new(false),
-            default_value: None,
-        }
+        Arg::new(name, Some(PrivateType::from(reading_type)), required)
     }
 
     /// Construct an `Arg` expecting no value.
@@ -466,15 +924,119 @@ impl Arg {
     /// let arg = Arg::without_value("foo", false);
     /// ```
     pub fn without_value(name: &str, required: bool) -> Arg {
+        Arg::new(name, None, required)
+    }
+
+    /// Construct an `Arg` taking an optional value, given only in the
+    /// "--name=value" form so that it never takes the next argument.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the argument.
+    /// * `reading_type` - The expected `Type` of the argument.
+    /// * `missing_value` - The value of the argument when given without value.
+    /// * `required` - Check if whether or not the argument is required.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match the optional argument --color or --color=<value>
+    /// let arg = Arg::with_optional_value(
+    ///     "color",
+    ///     Type::ReadAsString,
+    ///     Box::new(String::from("always")),
+    ///     false,
+    /// );
+    /// ```
+    pub fn with_optional_value(
+        name: &str,
+        reading_type: Type,
+        missing_value: Box<dyn Any>,
+        required: bool,
+    ) -> Arg {
+        Arg {
+            missing_value: Some(missing_value),
+            ..Arg::new(name, Some(PrivateType::from(reading_type)), required)
+        }
+    }
+
+    /// Construct an `Arg` expecting no value and counting its occurrences,
+    /// read the count with `CliArguments::get_count`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the argument.
+    /// * `required` - Check if whether or not the argument is required.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match the optional argument -vvv or --verbose --verbose
+    /// let arg = Arg::with_count("verbose", false);
+    /// arg.add_short('v');
+    /// ```
+    pub fn with_count(name: &str, required: bool) -> Arg {
+        Arg {
+            counting: true,
+            ..Arg::new(name, None, required)
+        }
+    }
+
+    /// Construct a positional `Arg`, matched by its place on the command
+    /// line rather than by its name.
+    ///
+    /// Positional arguments are filled in the order of their `index`,
+    /// the last one may be made variadic with `set_variadic`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the argument.
+    /// * `index` - The position of the argument among the positional ones.
+    /// * `reading_type` - The expected `Type` of the argument.
+    /// * `required` - Check if whether or not the argument is required.
+    ///
+    /// # Example
+    /// ```
+    /// # use parg::{Arg, Type};
+    /// // match "mytool <input> [output]"
+    /// let input = Arg::positional("input", 0, Type::ReadAsString, true);
+    /// let output = Arg::positional("output", 1, Type::ReadAsString, false);
+    /// ```
+    pub fn positional(name: &str, index: usize, reading_type: Type, required: bool) -> Arg {
+        Arg {
+            index: Some(index),
+            ..Arg::new(name, Some(PrivateType::from(reading_type)), required)
+        }
+    }
+
+    fn new(name: &str, type_read: Option<PrivateType>, required: bool) -> Arg {
         Arg {
             name: name.to_string(),
+            shorts: RefCell::new(Vec::new()),
+            aliases: RefCell::new(Vec::new()),
             description: RefCell::new(String::new()),
-            type_read: None,
+            has_value: type_read.is_some(),
+            type_read,
             required,
-            has_value: false,
             value: RefCell::new(None),
             found: Cell::new(false),
+            occurrences: Cell::new(0),
+            counting: false,
+            negatable: Cell::new(false),
+            negated: Cell::new(false),
+            allow_hyphen_values: Cell::new(false),
+            global: Cell::new(false),
+            conflicts: RefCell::new(Vec::new()),
+            requirements: RefCell::new(Vec::new()),
+            required_unless: RefCell::new(Vec::new()),
+            required_if_eq: RefCell::new(Vec::new()),
             default_value: None,
+            missing_value: None,
+            index: None,
+            variadic: Cell::new(None),
+            multiple: Cell::new(false),
+            num_values: Cell::new(None),
+            delimiter: Cell::new(None),
+            values: RefCell::new(Vec::new()),
+            raw_values: RefCell::new(Vec::new()),
         }
     }
 }
@@ -1,21 +1,126 @@
 use std::any::{Any, TypeId};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
+use std::cmp::Reverse;
 use std::collections::BTreeMap;
+use std::convert::TryInto;
+use std::ffi::{OsStr, OsString};
 use std::fmt;
+use std::iter::Peekable;
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus};
+use std::vec::IntoIter;
 use core::iter::Iterator;
 
 use crate::arg::{Arg, PrivateType};
+use crate::plugin;
+use crate::response_file;
+use crate::suggestion;
+
+/// A command line token once short clusters are expanded.
+#[derive(Debug)]
+enum Token {
+    /// A long argument "--name".
+    Long(String),
+    /// A short argument "-c".
+    Short(char),
+    /// A value glued to the previous argument, like "8" in "-j8".
+    Attached(OsString),
+    /// Anything else.
+    Plain(OsString),
+    /// A token following the "--" terminator, kept verbatim.
+    Trailing(OsString),
+    /// A subcommand name, with the raw arguments following it.
+    Subcommand(String, Vec<(OsString, String)>),
+}
+
+/// The `Token`s of a command line, each with its location.
+struct Tokens {
+    tokens: Peekable<IntoIter<(Token, String)>>,
+    /// The location of the last token read, "<file>:<line>: " for a token
+    /// read from a response file.
+    location: String,
+}
+
+impl Iterator for Tokens {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        let (token, location) = self.tokens.next()?;
+        self.location = location;
+        Some(token)
+    }
+}
+
+impl Tokens {
+    fn next_if(&mut self, func: impl FnOnce(&Token) -> bool) -> Option<Token> {
+        let (token, location) = self.tokens.next_if(|(token, _)| func(token))?;
+        self.location = location;
+        Some(token)
+    }
+}
+
+/// The prefixes introducing an argument on the command line.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrefixStyle {
+    /// "--name" and "-c", short names being clustered like "-xvf".
+    /// This is the default.
+    Gnu,
+    /// "-name" or "--name", like the flag package of Go.
+    /// Single character names also match short names.
+    SingleDashLong,
+    /// "/name", like legacy Windows tools.
+    /// Single character names also match short names.
+    Slash,
+    /// Any of the given prefixes, like "+" for "+feature".
+    /// Single character names also match short names.
+    Custom(Vec<String>),
+}
+
+/// How many members of a group of `Arg`s may be given.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GroupRule {
+    /// At most one member, like "--json" or "--yaml".
+    AtMostOne,
+    /// At least one member, like "--id", "--name" or "--tag".
+    AtLeastOne,
+    /// Exactly one member.
+    ExactlyOne,
+}
+
+/// A named group of `Arg`s following a `GroupRule`.
+struct Group {
+    name: String,
+    members: Vec<String>,
+    rule: GroupRule,
+}
 
 /// Argument Engine looking for all `Arg`.
 pub struct CliArguments<'a> {
     app_name: RefCell<String>,
     description: RefCell<String>,
-    named_args: BTreeMap<String, &'a Arg>,
+    named_args: RefCell<BTreeMap<String, &'a Arg>>,
+    trailing_args: RefCell<Vec<OsString>>,
+    allow_unknown: Cell<bool>,
+    abbreviations: Cell<bool>,
+    separator_insensitive: Cell<bool>,
+    prefix_style: RefCell<PrefixStyle>,
+    response_files: Cell<bool>,
+    subcommands: RefCell<BTreeMap<String, &'a CliArguments<'a>>>,
+    subcommand_aliases: RefCell<BTreeMap<String, String>>,
+    infer_subcommands: Cell<bool>,
+    matched_subcommand: RefCell<Option<String>>,
+    inherited_args: RefCell<Vec<String>>,
+    /// The named `Arg`s of the parent commands, the closest first, the
+    /// global ones being inherited.
+    parent_args: RefCell<Vec<(String, &'a Arg)>>,
+    external_subcommands: Cell<bool>,
+    external_subcommand: RefCell<Option<(String, Vec<OsString>)>>,
+    groups: RefCell<Vec<Group>>,
 }
 
 impl fmt::Display for CliArguments<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        for (_, arg) in self.named_args.iter() {
+        for (_, arg) in self.named_args.borrow().iter() {
             writeln!(f, "{}", arg)?;
         }
         write!(f, "")
@@ -60,47 +165,223 @@ impl<'a> CliArguments<'a> {
     /// let cli: CliArguments = create_cli_arguments!(&a, &b, &c);
     /// # }
     /// ```
-    pub fn new(named_args: BTreeMap<String, &'a Arg>) -> CliArguments {
+    pub fn new(named_args: BTreeMap<String, &'a Arg>) -> CliArguments<'a> {
         CliArguments {
             app_name: RefCell::new(String::new()),
             description: RefCell::new(String::new()),
-            named_args,
+            named_args: RefCell::new(named_args),
+            trailing_args: RefCell::new(Vec::new()),
+            allow_unknown: Cell::new(false),
+            abbreviations: Cell::new(false),
+            separator_insensitive: Cell::new(false),
+            prefix_style: RefCell::new(PrefixStyle::Gnu),
+            response_files: Cell::new(false),
+            subcommands: RefCell::new(BTreeMap::new()),
+            subcommand_aliases: RefCell::new(BTreeMap::new()),
+            infer_subcommands: Cell::new(false),
+            matched_subcommand: RefCell::new(None),
+            inherited_args: RefCell::new(Vec::new()),
+            parent_args: RefCell::new(Vec::new()),
+            external_subcommands: Cell::new(false),
+            external_subcommand: RefCell::new(None),
+            groups: RefCell::new(Vec::new()),
         }
     }
 
-    fn check_args(&self) -> Result<(), String> {
-        for (name, arg) in self.named_args.iter() {
+    /// Check the `Arg`s, except the global ones in `parent_checked`.
+    fn check_args(&self, parent_checked: &[String]) -> Result<(), String> {
+        for (name, arg) in self.named_args.borrow().iter() {
+            if parent_checked.contains(name) {
+                continue;
+            }
             if !arg.found.get() {
                 if !arg.has_default_value() {
                     if arg.required {
                         return Err(format!(
-                            "Argument --{} is required !\n{}",
-                            name,
+                            "Argument {} is required !\n{}",
+                            self.display_name(arg),
                             self.generate_usage()
                         ));
                     }
+                    self.check_required_conditions(arg)?;
                 } else {
                     arg.accept_default_value()?;
                 }
             }
 
-            if arg.found.get() && arg.has_value {
-                if arg.value.borrow().is_none() {
-                    if !arg.has_default_value() {
-                        return Err(format!(
-                            "Argument --{} needs a value !\n{}",
-                            name,
-                            self.generate_usage()
-                        ));
-                    } else {
-                        arg.accept_default_value()?;
-                    }
+            if let Some((min, _)) = arg.variadic.get() {
+                let count = arg.values.borrow().len();
+                if count < min {
+                    return Err(format!(
+                        "Argument {} needs at least {} values, got {} !\n{}",
+                        self.display_name(arg),
+                        min,
+                        count,
+                        self.generate_usage()
+                    ));
+                }
+            } else if arg.found.get() && arg.has_value && !arg.has_read_value() {
+                if !arg.has_default_value() {
+                    return Err(format!(
+                        "Argument {} needs a value !\n{}",
+                        self.display_name(arg),
+                        self.generate_usage()
+                    ));
+                } else {
+                    arg.accept_default_value()?;
+                }
+            }
+        }
+        self.check_conflicts()?;
+        self.check_groups()?;
+        self.check_requirements()
+    }
+
+    /// Check that no conflicting `Arg`s were given together.
+    fn check_conflicts(&self) -> Result<(), String> {
+        let named_args = self.named_args.borrow();
+        let given = |name: &String| match named_args.get(name) {
+            Some(arg) if arg.found.get() && !arg.negated.get() => Some(*arg),
+            _ => None,
+        };
+        for (name, arg) in named_args.iter() {
+            if given(name).is_none() {
+                continue;
+            }
+            if let Some(conflict) = arg.conflicts.borrow().iter().find_map(given) {
+                return Err(self.conflict_error(arg, conflict));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that each group has as many members given as its rule allows.
+    fn check_groups(&self) -> Result<(), String> {
+        let named_args = self.named_args.borrow();
+        for group in self.groups.borrow().iter() {
+            let given: Vec<&Arg> = group
+                .members
+                .iter()
+                .filter(|member| self.is_given(member))
+                .map(|member| named_args[member])
+                .collect();
+            let members: Vec<String> = group
+                .members
+                .iter()
+                .map(|member| self.display_arg_name(member))
+                .collect();
+            match (group.rule, &given[..]) {
+                (GroupRule::AtMostOne, [first, second, ..]) => {
+                    return Err(self.conflict_error(first, second));
+                }
+                (GroupRule::ExactlyOne, [first, second, ..]) => {
+                    return Err(format!(
+                        "Argument {} cannot be used with {}, exactly one of {} is required !\n{}",
+                        self.display_name(first),
+                        self.display_name(second),
+                        members.join(", "),
+                        self.generate_usage()
+                    ));
+                }
+                (GroupRule::AtLeastOne, []) | (GroupRule::ExactlyOne, []) => {
+                    return Err(format!(
+                        "One of {} is required !\n{}",
+                        members.join(", "),
+                        self.generate_usage()
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the conditions making the absent `arg` required.
+    fn check_required_conditions(&self, arg: &Arg) -> Result<(), String> {
+        let required_unless = arg.required_unless.borrow();
+        if !required_unless.is_empty() && !required_unless.iter().any(|name| self.is_given(name)) {
+            let names: Vec<String> = required_unless
+                .iter()
+                .map(|name| self.display_arg_name(name))
+                .collect();
+            return Err(format!(
+                "Argument {} is required unless {} is given !\n{}",
+                self.display_name(arg),
+                names.join(" or "),
+                self.generate_usage()
+            ));
+        }
+        for (name, value) in arg.required_if_eq.borrow().iter() {
+            if self.is_given_with(name, value) {
+                return Err(format!(
+                    "Argument {} is required when {}={} !\n{}",
+                    self.display_name(arg),
+                    self.display_arg_name(name),
+                    value,
+                    self.generate_usage()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if the `Arg` named `name` was given, and not negated.
+    fn is_given(&self, name: &str) -> bool {
+        matches!(
+            self.named_args.borrow().get(name),
+            Some(arg) if arg.found.get() && !arg.negated.get()
+        )
+    }
+
+    /// Check if the `Arg` named `name` was given with the value `value`.
+    fn is_given_with(&self, name: &str, value: &str) -> bool {
+        match self.named_args.borrow().get(name) {
+            Some(arg) if self.is_given(name) => {
+                arg.raw_values.borrow().iter().any(|raw| raw == value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check that the `Arg`s needed by the given ones were given too.
+    fn check_requirements(&self) -> Result<(), String> {
+        let named_args = self.named_args.borrow();
+        for (name, arg) in named_args.iter() {
+            if !self.is_given(name) {
+                continue;
+            }
+            for (value, required) in arg.requirements.borrow().iter() {
+                if self.is_given(required) {
+                    continue;
                 }
+                let trigger = match value {
+                    Some(value) if self.is_given_with(name, value) => {
+                        format!("{}={}", self.display_name(arg), value)
+                    }
+                    Some(_) => continue,
+                    None => self.display_name(arg),
+                };
+                return Err(format!(
+                    "Argument {} requires {} !\n{}",
+                    trigger,
+                    self.display_arg_name(required),
+                    self.generate_usage()
+                ));
             }
         }
         Ok(())
     }
 
+    /// Build the error message of two conflicting `Arg`s.
+    fn conflict_error(&self, arg: &Arg, other: &Arg) -> String {
+        format!(
+            "Argument {} cannot be used with {} !\n{}",
+            self.display_name(arg),
+            self.display_name(other),
+            self.generate_usage()
+        )
+    }
+
     fn check_type(&self, type_id: TypeId, type_read: &PrivateType) -> bool {
         let type_read_type_id = match type_read {
             PrivateType::ReadAsU8(sample) => sample.type_id(),
@@ -120,6 +401,8 @@ impl<'a> CliArguments<'a> {
             PrivateType::ReadAsBool(sample) => sample.type_id(),
             PrivateType::ReadAsChar(sample) => sample.type_id(),
             PrivateType::ReadAsString(sample) => sample.type_id(),
+            PrivateType::ReadAsPathBuf(sample) => sample.type_id(),
+            PrivateType::ReadAsOsString(sample) => sample.type_id(),
         };
         type_id == type_read_type_id
     }
@@ -161,8 +444,8 @@ impl<'a> CliArguments<'a> {
     /// # }
     /// ```
     pub fn exists(&self, arg_name: &str) -> bool {
-        if let Some(arg) = self.named_args.get(arg_name) {
-            arg.found.get()
+        if let Some(arg) = self.named_args.borrow().get(arg_name) {
+            arg.found.get() && !arg.negated.get()
         } else {
             false
         }
@@ -170,25 +453,160 @@ impl<'a> CliArguments<'a> {
 
     /// Generate a text to explain usage
     pub fn generate_usage(&self) -> String {
+        self.usage(false)
+    }
+
+    /// Generate the text printed by "--help", the usage also listing the
+    /// plugins found in `PATH` when external subcommands are enabled.
+    pub fn generate_help(&self) -> String {
+        self.usage(true)
+    }
+
+    fn usage(&self, list_plugins: bool) -> String {
         let mut params = String::new();
-        let mut params_descr = format!("{:23}Print this help\n", "--help");
-        for (name, arg) in self.named_args.iter() {
-            params = format!("{} --{} <value>", params, name);
+        let long_prefix = self.long_prefix();
+        let short_prefix = self.short_prefix();
+        let help = if self.find_short('h').is_none() {
+            format!("{}h, {}help", short_prefix, long_prefix)
+        } else {
+            format!("{}help", long_prefix)
+        };
+        let mut params_descr = format!("{:23}Print this help\n", help);
+        let mut usages: Vec<(String, String)> = Vec::new();
+        // the global `Arg`s of the parents, even before a parse
+        let named_args = self.visible_args();
+        for (name, arg) in named_args.iter().filter(|(_, arg)| !arg.is_positional()) {
+            let mut shorts = String::new();
+            for short in arg.shorts.borrow().iter() {
+                shorts = format!("{}{}{}, ", shorts, short_prefix, short);
+            }
+            let long = if arg.negatable.get() {
+                format!("[no-]{}", name)
+            } else {
+                String::from(name)
+            };
+            let placeholder = if arg.counting {
+                String::from("...")
+            } else if arg.has_optional_value() {
+                String::from("[=<value>]")
+            } else if let Some((min, max)) = arg.num_values.get() {
+                let mut placeholders = vec!["<value>"; min];
+                placeholders.resize(max, "[<value>]");
+                placeholders.join(" ")
+            } else if arg.has_value {
+                String::from("<value>")
+            } else {
+                String::new()
+            };
+            let dots = if arg.multiple.get() { "..." } else { "" };
+            let usage = if arg.counting {
+                format!("{}{}...", long_prefix, long)
+            } else if arg.has_optional_value() || !arg.has_value {
+                format!("{}{}{}{}", long_prefix, long, placeholder, dots)
+            } else {
+                format!("{}{} {}{}", long_prefix, long, placeholder, dots)
+            };
+            usages.push((String::from(name), usage));
+            params_descr = format!(
+                "{}{}{}{} {:10}    {} (default: {})\n",
+                params_descr,
+                shorts,
+                long_prefix,
+                long,
+                placeholder,
+                arg.description.borrow(),
+                arg.format_default_value()
+            );
+            let aliases: Vec<String> = arg
+                .aliases
+                .borrow()
+                .iter()
+                .map(|alias| format!("{}{}", long_prefix, alias))
+                .collect();
+            if !aliases.is_empty() {
+                params_descr = format!("{}    aliases: {}\n", params_descr, aliases.join(", "));
+            }
+            let required_unless: Vec<String> = arg
+                .required_unless
+                .borrow()
+                .iter()
+                .map(|name| format!("{}{}", long_prefix, name))
+                .collect();
+            if !required_unless.is_empty() {
+                params_descr = format!(
+                    "{}    required unless {} is given\n",
+                    params_descr,
+                    required_unless.join(" or ")
+                );
+            }
+            for (name, value) in arg.required_if_eq.borrow().iter() {
+                params_descr = format!(
+                    "{}    required when {}{}={}\n",
+                    params_descr, long_prefix, name, value
+                );
+            }
+        }
+        params = format!("{}{}", params, self.join_usages(&usages));
+        for arg in self.positionals() {
+            let required = arg.required || matches!(arg.variadic.get(), Some((min, _)) if min > 0);
+            let display_name = self.display_name(arg);
+            if required {
+                params = format!("{} {}", params, display_name);
+            } else {
+                params = format!("{} [{}]", params, &display_name[1..display_name.len() - 1]);
+            }
             params_descr = format!(
-                "{}--{} {:10}    {} (default: {})\n",
+                "{}{:15}    {} (default: {})\n",
                 params_descr,
-                name,
-                "<value>",
+                display_name,
                 arg.description.borrow(),
                 arg.format_default_value()
             );
         }
+        let subcommands = self.subcommands.borrow();
+        let mut commands: Vec<(String, String)> = subcommands
+            .iter()
+            .map(|(name, subcommand)| (String::from(name), subcommand.description.borrow().clone()))
+            .collect();
+        if list_plugins && self.external_subcommands.get() {
+            for (name, path) in plugin::find_all(&self.plugin_prefix()) {
+                if !subcommands.contains_key(&name) {
+                    commands.push((name, format!("plugin {}", path.display())));
+                }
+            }
+            commands.sort();
+        }
+        let mut commands_descr = String::new();
+        if !commands.is_empty() {
+            params = format!("{} <command>", params);
+            for (name, description) in commands.iter() {
+                commands_descr = format!("{}{:15}    {}\n", commands_descr, name, description);
+                let aliases: Vec<String> = self
+                    .subcommand_aliases
+                    .borrow()
+                    .iter()
+                    .filter(|(_, subcommand)| *subcommand == name)
+                    .map(|(alias, _)| String::from(alias))
+                    .collect();
+                if !aliases.is_empty() {
+                    commands_descr =
+                        format!("{}    aliases: {}\n", commands_descr, aliases.join(", "));
+                }
+            }
+            commands_descr = format!(
+                "\nCommands:\n{}\nSee '{} <command> {}help' for the help of a command.\n",
+                commands_descr,
+                self.app_name.borrow(),
+                long_prefix
+            );
+        }
         format!(
-            "{}\nUsage:\n{}{}\n\nArguments:\n{}",
+            "{}\nUsage:\n{}{}\n\nArguments:\n{}{}",
             self.description.borrow(),
             self.app_name.borrow(),
             params,
-            params_descr
+            params_descr,
+            commands_descr
         )
     }
 
@@ -228,20 +646,13 @@ impl<'a> CliArguments<'a> {
     /// # }
     /// ```
     pub fn get_value<T: 'static + Clone>(&self, arg_name: &str) -> T {
-        if let Some(arg) = self.named_args.get(arg_name) {
+        if let Some(arg) = self.named_args.borrow().get(arg_name) {
+            if arg.is_multiple() {
+                panic!("Argument \"{}\" takes several values, use get_values !", arg_name);
+            }
             if arg.has_value {
                 // check that types match
-                if let Some(type_read) = &arg.type_read {
-                    let is_type_conform = self.check_type(TypeId::of::<T>(), type_read);
-                    let is_option_type_conform =
-                        self.check_type(TypeId::of::<Option<T>>(), type_read);
-                    if !is_type_conform && !is_option_type_conform {
-                        panic!(
-                            "The requested type for \"{}\" does not match the reading type !",
-                            arg_name
-                        );
-                    }
-                }
+                self.assert_type::<T>(arg_name, arg);
 
                 // access to the value
                 let borrowed_value = arg.value.borrow();
@@ -266,8 +677,249 @@ impl<'a> CliArguments<'a> {
         }
     }
 
+    ///  Get all the values of the `arg_name` argument.
+    ///
+    /// # Arguments
+    /// * `arg_name` - The name of the `Arg` to get the values of.
+    ///
+    /// # Returns
+    /// Return a `Vec<T>`, T being the requested type, in command line order.
+    /// For an `Arg` taking a single value, this is that value or the
+    /// default one, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let input = Arg::positional("input", 0, Type::ReadAsString, true);
+    /// let output = Arg::positional("output", 1, Type::ReadAsString, false);
+    /// output.set_variadic(0, None);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&input, &output);
+    ///
+    /// let args = vec!["in.txt", "a.txt", "b.txt"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// let output: Vec<String> = cli.get_values("output");
+    /// assert_eq!(output, vec!["a.txt", "b.txt"]);
+    /// # }
+    /// ```
+    pub fn get_values<T: 'static + Clone>(&self, arg_name: &str) -> Vec<T> {
+        if let Some(arg) = self.named_args.borrow().get(arg_name) {
+            if arg.has_value {
+                // check that types match
+                self.assert_type::<T>(arg_name, arg);
+
+                let downcast = |value: &Box<dyn Any>| match value.downcast_ref::<T>() {
+                    Some(v) => v.clone(),
+                    None => panic!("Error downcasting argument {}", arg_name),
+                };
+                if arg.is_multiple() {
+                    arg.values.borrow().iter().map(downcast).collect()
+                } else {
+                    match (arg.value.borrow().as_ref(), arg.default_value.as_ref()) {
+                        (Some(value), _) | (None, Some(value)) => vec![downcast(value)],
+                        (None, None) => Vec::new(),
+                    }
+                }
+            } else {
+                panic!("Argument {} does not take a value !", arg_name);
+            }
+        } else {
+            panic!("Argument \"{}\" does not exists !", arg_name)
+        }
+    }
+
+    ///  Get how many times the `arg_name` argument was found.
+    ///
+    /// # Arguments
+    /// * `arg_name` - The name of the `Arg` to count.
+    ///
+    /// # Returns
+    /// Return the number of occurrences, `0` if absent. For a negatable
+    /// `Arg`, only the occurrences following the last "--no-<name>" count.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let verbose = Arg::with_count("verbose", false);
+    /// verbose.add_short('v');
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    ///
+    /// let args = vec!["-vv", "--verbose"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// assert_eq!(cli.get_count("verbose"), 3);
+    /// # }
+    /// ```
+    pub fn get_count(&self, arg_name: &str) -> usize {
+        match self.named_args.borrow().get(arg_name) {
+            Some(arg) => arg.occurrences.get(),
+            None => panic!("Argument \"{}\" does not exists !", arg_name),
+        }
+    }
+
+    ///  Get the state of the negatable `arg_name` flag.
+    ///
+    /// # Arguments
+    /// * `arg_name` - The name of the `Arg` to get the state of.
+    ///
+    /// # Returns
+    /// Return `Some(true)` if the last occurrence was "--<name>",
+    /// `Some(false)` if it was "--no-<name>" and `None` if the flag is absent,
+    /// so that `unwrap_or` gives the fallback.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let color = Arg::without_value("color", false);
+    /// color.set_negatable(true);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&color);
+    ///
+    /// let args = vec!["--color", "--no-color"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// assert_eq!(cli.get_toggle("color"), Some(false));
+    /// let color_enabled = cli.get_toggle("color").unwrap_or(true);
+    /// # }
+    /// ```
+    pub fn get_toggle(&self, arg_name: &str) -> Option<bool> {
+        match self.named_args.borrow().get(arg_name) {
+            Some(arg) if arg.found.get() => Some(!arg.negated.get()),
+            Some(_) => None,
+            None => panic!("Argument \"{}\" does not exists !", arg_name),
+        }
+    }
+
+    ///  Get the arguments following the "--" terminator.
+    ///
+    /// # Returns
+    /// Return the tokens after "--", verbatim and in command line order.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let verbose = Arg::without_value("verbose", false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    ///
+    /// let args = vec!["--verbose", "--", "--flag-for-child", "file"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// assert_eq!(cli.get_trailing_args(), vec!["--flag-for-child", "file"]);
+    /// # }
+    /// ```
+    pub fn get_trailing_args(&self) -> Vec<String> {
+        self.trailing_args
+            .borrow()
+            .iter()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect()
+    }
+
+    ///  Get the arguments following the "--" terminator, as given even
+    ///  when they are not valid unicode.
+    ///
+    /// # Returns
+    /// Return the tokens after "--", verbatim and in command line order.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # use std::ffi::OsString;
+    /// # fn main() {
+    /// let verbose = Arg::without_value("verbose", false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    ///
+    /// let args = vec!["--verbose", "--", "file"];
+    /// cli.parse_subset_os(args.into_iter().map(OsString::from)).unwrap();
+    ///
+    /// assert_eq!(cli.get_trailing_args_os(), vec![OsString::from("file")]);
+    /// # }
+    /// ```
+    pub fn get_trailing_args_os(&self) -> Vec<OsString> {
+        self.trailing_args.borrow().clone()
+    }
+
+    ///  Get the values of the `arg_name` argument as a fixed-size array.
+    ///
+    /// # Arguments
+    /// * `arg_name` - The name of the `Arg` to get the values of.
+    ///
+    /// # Returns
+    /// Return a `[T; N]`, T being the requested type and N the count of values.
+    /// Panics if the argument does not have exactly N values.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let point = Arg::with_value("point", Type::ReadAsI32, true);
+    /// point.set_num_values(2, 2);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&point);
+    ///
+    /// let args = vec!["--point", "3", "4"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// let [x, y]: [i32; 2] = cli.get_array("point");
+    /// assert_eq!((x, y), (3, 4));
+    /// # }
+    /// ```
+    pub fn get_array<T: 'static + Clone, const N: usize>(&self, arg_name: &str) -> [T; N] {
+        let values = self.get_values::<T>(arg_name);
+        let count = values.len();
+        match values.try_into() {
+            Ok(array) => array,
+            Err(_) => panic!(
+                "Argument \"{}\" has {} values, {} requested !",
+                arg_name, count, N
+            ),
+        }
+    }
+
+    /// Panic if `T` is not the reading type of `arg`.
+    fn assert_type<T: 'static>(&self, arg_name: &str, arg: &Arg) {
+        if let Some(type_read) = &arg.type_read {
+            let is_type_conform = self.check_type(TypeId::of::<T>(), type_read);
+            let is_option_type_conform = self.check_type(TypeId::of::<Option<T>>(), type_read);
+            if !is_type_conform && !is_option_type_conform {
+                panic!(
+                    "The requested type for \"{}\" does not match the reading type !",
+                    arg_name
+                );
+            }
+        }
+    }
+
     ///  Parse the command line arguments.
     ///
+    ///  Panics if an argument is not valid unicode, see `parse_os`.
+    ///
     /// # Returns
     /// Return a `Result<(), String>`, String being the error message if any.
     ///
@@ -293,59 +945,962 @@ impl<'a> CliArguments<'a> {
     /// # }
     /// ```
     pub fn parse(&self) -> Result<(), String> {
-        self.internal_parse(std::env::args().skip(1))
+        self.internal_parse(std::env::args().skip(1).map(OsString::from))
     }
 
     pub fn parse_subset<T>(&self, args: T) -> Result<(), String>
         where
             T: Iterator<Item = String>
     {
-        self.internal_parse(args)
-    }
-
-    fn internal_parse<T>(&self, args: T) -> Result<(), String>
-        where
-            T: Iterator<Item = String>
-    {
-        self.reset_args();
-        let mut last_arg_name = String::new();
-        let mut read_value = false;
-        for arg in args {
-            if read_value {
-                read_value = false;
-                self.read_value(String::from(&arg), String::from(&last_arg_name))?;
-            }
-
-            if arg.starts_with("--") && arg.chars().count() >= 3 {
-                if arg.eq_ignore_ascii_case("--help") {
-                    println!("{}", self.generate_usage());
-                    return Err(String::new());
-                }
-                last_arg_name = String::from(&arg[2..]);
-                if let Some(argument) = self.named_args.get(&last_arg_name) {
-                    read_value = true;
-                    argument.found.set(true);
-                }
-            }
-        }
-
-        // make checks
-        self.check_args()?;
-        Ok(())
+        self.internal_parse(args.map(OsString::from))
     }
 
-    fn read_value(&self, arg: String, arg_name: String) -> Result<(), String> {
-        if let Some(argument) = self.named_args.get(&arg_name) {
-            if argument.has_value {
-                let value: Box<dyn Any> = match &argument.type_read {
-                    Some(PrivateType::ReadAsU8(_)) => {
-                        let tmp = arg.parse::<u8>().map_err(|e| {
-                            format!("Argument value {} for {} must be u8: {}", arg, arg_name, e)
-                        })?;
-                        Box::new(tmp)
-                    }
-                    Some(PrivateType::ReadAsU16(_)) => {
-                        let tmp = arg.parse::<u16>().map_err(|e| {
+    ///  Parse the command line arguments, which may not be valid unicode.
+    ///
+    ///  Unlike `parse`, this does not panic on such arguments: they are
+    ///  kept as is by `Type::ReadAsPathBuf` and `Type::ReadAsOsString`
+    ///  and reported as an error by the other `Type`s.
+    ///
+    /// # Returns
+    /// Return a `Result<(), String>`, String being the error message if any.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let a = Arg::with_value("path", Type::ReadAsPathBuf, true);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    ///
+    /// // parse args and get return status
+    /// let return_status = cli.parse_os();
+    /// if let Err(msg) = return_status {
+    ///     eprintln!("{}", msg);
+    ///     return;
+    /// }
+    /// # }
+    /// ```
+    pub fn parse_os(&self) -> Result<(), String> {
+        self.internal_parse(std::env::args_os().skip(1))
+    }
+
+    ///  Parse the given arguments, which may not be valid unicode.
+    ///
+    /// # Arguments
+    /// * `args` - The arguments to parse, without the program name.
+    ///
+    /// # Returns
+    /// Return a `Result<(), String>`, String being the error message if any.
+    ///
+    /// # Example
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # use std::ffi::OsString;
+    /// # use std::path::PathBuf;
+    /// # fn main() {
+    /// let a = Arg::with_value("path", Type::ReadAsPathBuf, true);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    ///
+    /// let args = vec![OsString::from("--path"), OsString::from("/tmp/file")];
+    /// cli.parse_subset_os(args.into_iter()).unwrap();
+    ///
+    /// let path: PathBuf = cli.get_value("path");
+    /// # }
+    /// ```
+    pub fn parse_subset_os<T>(&self, args: T) -> Result<(), String>
+        where
+            T: Iterator<Item = OsString>
+    {
+        self.internal_parse(args)
+    }
+
+    fn internal_parse<T>(&self, args: T) -> Result<(), String>
+        where
+            T: Iterator<Item = OsString>
+    {
+        self.parse_located(args.map(|arg| (arg, String::new())), false)
+    }
+
+    /// Parse arguments given with their location, the errors caused by an
+    /// argument read from a response file starting with "<file>:<line>: ".
+    /// When `dispatched` by the parent command, the global `Arg`s are reset
+    /// and checked by the parent.
+    fn parse_located<T>(&self, args: T, dispatched: bool) -> Result<(), String>
+        where
+            T: Iterator<Item = (OsString, String)>
+    {
+        self.inherit_args();
+        let parent_checked = if dispatched {
+            self.inherited_args.borrow().clone()
+        } else {
+            Vec::new()
+        };
+        self.check_positionals()?;
+        self.check_default_values()?;
+        self.check_references();
+        self.reset_args(&parent_checked);
+        let args = if self.response_files.get() {
+            response_file::expand(args)?
+        } else {
+            args.collect()
+        };
+        let mut tokens = Tokens {
+            tokens: self.tokenize(args.into_iter()).into_iter().peekable(),
+            location: String::new(),
+        };
+        self.parse_tokens(&mut tokens).map_err(|msg| {
+            if msg.is_empty() {
+                msg
+            } else {
+                format!("{}{}", tokens.location, msg)
+            }
+        })?;
+
+        // make checks
+        self.check_args(&parent_checked)?;
+        Ok(())
+    }
+
+    fn parse_tokens(&self, tokens: &mut Tokens) -> Result<(), String> {
+        let positionals = self.positionals();
+        while let Some(token) = tokens.next() {
+            let mut negated = false;
+            let arg_name = match token {
+                Token::Long(name) => {
+                    let is_help_short = !self.has_short_clusters()
+                        && name == "h"
+                        && self.find_long(&name).is_none();
+                    if name.eq_ignore_ascii_case("help") || is_help_short {
+                        println!("{}", self.generate_help());
+                        return Err(String::new());
+                    }
+                    match self.resolve_long(&name)? {
+                        Some((name, is_negated)) => {
+                            negated = is_negated;
+                            name
+                        }
+                        None => name,
+                    }
+                }
+                Token::Short(short) => match self.find_short(short) {
+                    Some(name) => name,
+                    None if short == 'h' => {
+                        println!("{}", self.generate_help());
+                        return Err(String::new());
+                    }
+                    None if self.allow_unknown.get() => continue,
+                    None => {
+                        return Err(format!("unknown argument {}{}", self.short_prefix(), short))
+                    }
+                },
+                Token::Plain(value) => {
+                    let positional = positionals.iter().find(|arg| arg.accepts_more_values());
+                    if let Some(positional) = positional {
+                        positional.found.set(true);
+                        positional.occurrences.set(positional.occurrences.get() + 1);
+                        self.read_values(value, positional.get_name())?;
+                    } else if !self.allow_unknown.get() {
+                        let value = value.to_string_lossy();
+                        if value.starts_with('-') && value.chars().count() >= 2 {
+                            return Err(format!("unknown argument {}", value));
+                        }
+                        if !self.subcommands.borrow().is_empty() {
+                            return Err(self.unknown_subcommand(&value));
+                        }
+                        return Err(format!("unexpected argument {}", value));
+                    }
+                    continue;
+                }
+                Token::Trailing(value) => {
+                    self.trailing_args.borrow_mut().push(value);
+                    continue;
+                }
+                Token::Subcommand(name, args) => {
+                    match self.resolve_subcommand(&name)? {
+                        Some(name) => {
+                            let subcommand = self.subcommands.borrow()[&name];
+                            // the subcommand locates its own errors
+                            tokens.location.clear();
+                            subcommand.parse_located(args.into_iter(), true)?;
+                            self.matched_subcommand.replace(Some(name));
+                        }
+                        None => {
+                            let args = args.into_iter().map(|(arg, _)| arg).collect();
+                            self.external_subcommand.replace(Some((name, args)));
+                        }
+                    }
+                    continue;
+                }
+                Token::Attached(_) => continue,
+            };
+
+            let argument = self
+                .named_args
+                .borrow()
+                .get(&arg_name)
+                .copied()
+                .filter(|arg| !arg.is_positional());
+            if argument.is_none() && !self.allow_unknown.get() {
+                return Err(self.unknown_long(&arg_name));
+            }
+            if let Some(argument) = argument {
+                argument.found.set(true);
+                // "--no-<name>" is no occurrence, and resets the count
+                if negated {
+                    argument.occurrences.set(0);
+                } else {
+                    argument.occurrences.set(argument.occurrences.get() + 1);
+                }
+                argument.negated.set(negated);
+                if argument.has_optional_value() {
+                    match tokens.next_if(|next| matches!(next, Token::Attached(_))) {
+                        Some(Token::Attached(value)) => self.read_values(value, arg_name)?,
+                        _ => argument.accept_missing_value()?,
+                    }
+                } else if let Some((min, max)) = argument.num_values.get() {
+                    if !argument.multiple.get() {
+                        argument.values.borrow_mut().clear();
+                        argument.raw_values.borrow_mut().clear();
+                    }
+                    let mut count = 0;
+                    if let Some(Token::Attached(value)) =
+                        tokens.next_if(|next| matches!(next, Token::Attached(_)))
+                    {
+                        self.read_values(value, String::from(&arg_name))?;
+                        count += 1;
+                    }
+                    while count < max {
+                        match tokens.next_if(|next| matches!(next, Token::Plain(_))) {
+                            Some(Token::Plain(value)) => {
+                                self.read_values(value, String::from(&arg_name))?
+                            }
+                            _ => break,
+                        }
+                        count += 1;
+                    }
+                    if count < min {
+                        return Err(format!(
+                            "Argument {} needs at least {} values, got {} !\n{}",
+                            self.display_name(argument),
+                            min,
+                            count,
+                            self.generate_usage()
+                        ));
+                    }
+                } else if argument.has_value {
+                    let value = tokens
+                        .next_if(|next| matches!(next, Token::Attached(_) | Token::Plain(_)));
+                    if let Some(Token::Attached(value)) | Some(Token::Plain(value)) = value {
+                        self.read_values(value, arg_name)?;
+                    }
+                } else if let Some(Token::Attached(value)) =
+                    tokens.next_if(|next| matches!(next, Token::Attached(_)))
+                {
+                    return Err(format!(
+                        "Argument {} does not take a value, got {} !\n{}",
+                        self.display_name(argument),
+                        value.to_string_lossy(),
+                        self.generate_usage()
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Split the raw arguments into `Token`s, expanding short clusters:
+    /// "-xvf archive" gives "-x -v -f archive" and "-j8" gives "-j 8".
+    /// A short argument taking a value ends the cluster, the remaining
+    /// characters being its value.
+    /// Everything after "--" is kept verbatim.
+    /// The first plain token naming a subcommand, or any plain token when
+    /// external subcommands are enabled, ends the tokenization, the
+    /// following arguments being left to this subcommand.
+    /// Each token keeps the location of the argument it comes from.
+    fn tokenize<T>(&self, mut args: T) -> Vec<(Token, String)>
+        where
+            T: Iterator<Item = (OsString, String)>
+    {
+        let mut tokens = Vec::new();
+        let mut locations = Vec::new();
+        let mut location = String::new();
+        let mut terminated = false;
+        // argument still expecting values, with the count of these values
+        let mut expected: Option<(&Arg, usize)> = None;
+        while let Some((arg, arg_location)) = args.next() {
+            // the tokens of the previous argument share its location
+            locations.resize(tokens.len(), location);
+            location = arg_location;
+            let arg = match arg.into_string() {
+                Ok(arg) => arg,
+                Err(raw) => {
+                    // not unicode, so a value, "--name=value" or "-nvalue"
+                    let lossy = String::from(raw.to_string_lossy());
+                    let is_value = match expected {
+                        Some((argument, _)) => self.is_expected_value(argument, &lossy),
+                        None => false,
+                    };
+                    if terminated {
+                        tokens.push(Token::Trailing(raw));
+                    } else if let Some((name, value)) = self.split_long_os(&raw) {
+                        tokens.push(Token::Long(name));
+                        tokens.push(Token::Attached(value));
+                        expected = None;
+                    } else if let Some((shorts, value)) = self.split_short_os(&raw) {
+                        tokens.extend(shorts.into_iter().map(Token::Short));
+                        tokens.push(Token::Attached(value));
+                        expected = None;
+                    } else if !is_value && self.is_short_cluster(&lossy, expected.is_some()) {
+                        // unknown short names, reported when parsing
+                        tokens.extend(lossy.chars().skip(1).map(Token::Short));
+                        expected = None;
+                    } else {
+                        tokens.push(Token::Plain(raw));
+                        expected = self.consume_expected(expected);
+                    }
+                    continue;
+                }
+            };
+            let is_value = match expected {
+                Some((argument, _)) => self.is_expected_value(argument, &arg),
+                None => self.is_negative_positional(&arg),
+            };
+            if terminated {
+                tokens.push(Token::Trailing(OsString::from(arg)));
+            } else if arg == "--" {
+                terminated = true;
+                expected = None;
+            } else if is_value {
+                tokens.push(Token::Plain(OsString::from(arg)));
+                expected = self.consume_expected(expected);
+            } else if let Some(name) = self.strip_long_prefix(&arg) {
+                // "--name=value" gives "--name value", the value may be empty
+                let (name, value) = match name.find('=') {
+                    Some(index) => (&name[..index], Some(&name[index + 1..])),
+                    None => (name, None),
+                };
+                tokens.push(Token::Long(String::from(name)));
+                expected = match self.resolve_long(name) {
+                    Ok(Some((name, _))) => self.expect_values(&name),
+                    _ => None,
+                };
+                if let Some(value) = value {
+                    tokens.push(Token::Attached(OsString::from(value)));
+                    expected = self.consume_expected(expected);
+                }
+            } else if self.is_short_cluster(&arg, expected.is_some()) {
+                for (index, short) in arg.char_indices().skip(1) {
+                    tokens.push(Token::Short(short));
+                    let (takes_value, expect) = match self.find_short(short) {
+                        Some(name) => {
+                            let has_value = self.named_args.borrow()[&name].has_value;
+                            (has_value, self.expect_values(&name))
+                        }
+                        None => (false, None),
+                    };
+                    expected = expect;
+                    let rest = &arg[index + short.len_utf8()..];
+                    if takes_value && !rest.is_empty() {
+                        tokens.push(Token::Attached(OsString::from(rest)));
+                        expected = self.consume_expected(expected);
+                        break;
+                    }
+                }
+            } else if expected.is_none() && self.is_subcommand(&arg) {
+                tokens.push(Token::Subcommand(arg, args.collect()));
+                break;
+            } else {
+                tokens.push(Token::Plain(OsString::from(arg)));
+                expected = self.consume_expected(expected);
+            }
+        }
+        locations.resize(tokens.len(), location);
+        tokens.into_iter().zip(locations).collect()
+    }
+
+    /// Check if a plain token selects a subcommand.
+    fn is_subcommand(&self, token: &str) -> bool {
+        // an ambiguous prefix is reported when parsing
+        if !matches!(self.resolve_subcommand(token), Ok(None)) {
+            return true;
+        }
+        self.external_subcommands.get() && !token.is_empty() && self.positionals().is_empty()
+    }
+
+    /// Resolve a plain token into the name of the subcommand it selects,
+    /// through aliases and prefixes, `None` if there is no such subcommand.
+    fn resolve_subcommand(&self, token: &str) -> Result<Option<String>, String> {
+        let subcommands = self.subcommands.borrow();
+        let aliases = self.subcommand_aliases.borrow();
+        if subcommands.contains_key(token) {
+            return Ok(Some(String::from(token)));
+        }
+        if let Some(name) = aliases.get(token) {
+            return Ok(Some(String::from(name)));
+        }
+        if self.infer_subcommands.get() && !token.is_empty() {
+            let mut resolved: Vec<String> = subcommands
+                .keys()
+                .chain(aliases.keys())
+                .filter(|candidate| candidate.starts_with(token))
+                .map(|candidate| String::from(aliases.get(candidate).unwrap_or(candidate)))
+                .collect();
+            resolved.sort();
+            resolved.dedup();
+            match resolved.len() {
+                0 => {}
+                1 => return Ok(resolved.pop()),
+                _ => {
+                    return Err(format!(
+                        "ambiguous subcommand {}, could be {}",
+                        token,
+                        resolved.join(", ")
+                    ))
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Build the error message of an unknown subcommand, suggesting the
+    /// plugins too when external subcommands are enabled.
+    fn unknown_subcommand(&self, token: &str) -> String {
+        let mut candidates: Vec<String> = self
+            .subcommands
+            .borrow()
+            .keys()
+            .chain(self.subcommand_aliases.borrow().keys())
+            .cloned()
+            .collect();
+        if self.external_subcommands.get() {
+            let plugins = plugin::find_all(&self.plugin_prefix());
+            candidates.extend(plugins.into_iter().map(|(name, _)| name));
+        }
+        let suggestions = suggestion::closest(token, candidates.iter());
+        format!(
+            "unknown subcommand {}{}",
+            token,
+            suggestion::did_you_mean("", &suggestions)
+        )
+    }
+
+    /// Split a long argument which is not unicode, like "--path=<bytes>",
+    /// into its name and its raw value.
+    #[cfg(unix)]
+    fn split_long_os(&self, token: &OsStr) -> Option<(String, OsString)> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let bytes = token.as_bytes();
+        let equal = bytes.iter().position(|byte| *byte == b'=')?;
+        let name = self.strip_long_prefix(std::str::from_utf8(&bytes[..equal]).ok()?)?;
+        let value = OsStr::from_bytes(&bytes[equal + 1..]);
+        Some((String::from(name), value.to_os_string()))
+    }
+
+    /// Split a long argument which is not unicode, like "--path=<bytes>",
+    /// into its name and its value, lossily converted to unicode.
+    #[cfg(not(unix))]
+    fn split_long_os(&self, token: &OsStr) -> Option<(String, OsString)> {
+        let token = token.to_string_lossy();
+        let equal = token.find('=')?;
+        let name = self.strip_long_prefix(&token[..equal])?;
+        Some((String::from(name), OsString::from(&token[equal + 1..])))
+    }
+
+    /// Split a short cluster which is not unicode, like "-p<bytes>", into
+    /// its short names and the raw value of the last one, taking a value.
+    #[cfg(unix)]
+    fn split_short_os(&self, token: &OsStr) -> Option<(Vec<char>, OsString)> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let cluster = token.as_bytes().strip_prefix(b"-")?;
+        let valid = match std::str::from_utf8(cluster) {
+            Ok(_) => return None,
+            Err(e) => e.valid_up_to(),
+        };
+        let shorts = std::str::from_utf8(&cluster[..valid]).ok()?;
+        let (shorts, index) = self.split_short_names(shorts)?;
+        Some((shorts, OsStr::from_bytes(&cluster[index..]).to_os_string()))
+    }
+
+    /// Split a short cluster which is not unicode, like "-p<bytes>", into
+    /// its short names and the value of the last one, taking a value,
+    /// lossily converted to unicode.
+    #[cfg(not(unix))]
+    fn split_short_os(&self, token: &OsStr) -> Option<(Vec<char>, OsString)> {
+        let token = token.to_string_lossy();
+        let cluster = token.strip_prefix('-')?;
+        let (shorts, index) = self.split_short_names(cluster)?;
+        Some((shorts, OsString::from(&cluster[index..])))
+    }
+
+    /// Get the leading short names of `cluster` up to the first one taking
+    /// a value, with the index of this value.
+    fn split_short_names(&self, cluster: &str) -> Option<(Vec<char>, usize)> {
+        if !self.has_short_clusters() {
+            return None;
+        }
+        let mut shorts = Vec::new();
+        for (index, short) in cluster.char_indices() {
+            let name = self.find_short(short)?;
+            shorts.push(short);
+            if self.named_args.borrow()[&name].has_value {
+                return Some((shorts, index + short.len_utf8()));
+            }
+        }
+        None
+    }
+
+    /// Get the `Arg` named `arg_name` if it expects values after it,
+    /// with the maximal count of these values.
+    fn expect_values(&self, arg_name: &str) -> Option<(&'a Arg, usize)> {
+        let argument: &'a Arg = self.named_args.borrow().get(arg_name)?;
+        if !argument.has_value || argument.has_optional_value() {
+            return None;
+        }
+        match argument.num_values.get() {
+            Some((_, max)) if max > 0 => Some((argument, max)),
+            Some(_) => None,
+            None => Some((argument, 1)),
+        }
+    }
+
+    /// Account for one value read by the expecting `Arg`.
+    fn consume_expected(&self, expected: Option<(&'a Arg, usize)>) -> Option<(&'a Arg, usize)> {
+        match expected {
+            Some((argument, count)) if count > 1 => Some((argument, count - 1)),
+            _ => None,
+        }
+    }
+
+    /// Check if a token is a value of the `Arg` expecting one. A token
+    /// looking like an argument, starting with '-' or a long prefix, only is
+    /// when the `Arg` accepts it or, in the styles without short clusters,
+    /// when it names no known argument, like "/home" after "/out".
+    fn is_expected_value(&self, argument: &Arg, token: &str) -> bool {
+        if token == "--" {
+            return false;
+        }
+        let name = self.strip_long_prefix(token);
+        if !token.starts_with('-') && name.is_none() {
+            return false;
+        }
+        if argument.accepts_hyphen_value(token) {
+            return true;
+        }
+        match name {
+            Some(name) if !self.has_short_clusters() => !self.is_known_long(name),
+            _ => false,
+        }
+    }
+
+    /// Check if a token is a negative number filling a positional `Arg` in
+    /// the styles without short clusters, where "-5" could be read as
+    /// "-<name>", like `is_short_cluster` does in the GNU style.
+    fn is_negative_positional(&self, token: &str) -> bool {
+        !self.has_short_clusters()
+            && token.starts_with('-')
+            && token.parse::<f64>().is_ok()
+            && !matches!(self.strip_long_prefix(token), Some(name) if self.is_known_long(name))
+    }
+
+    /// Check if "--<name>", maybe followed by "=value", names an argument,
+    /// the help included.
+    fn is_known_long(&self, name: &str) -> bool {
+        let name = name.split('=').next().unwrap_or(name);
+        let is_help_short = !self.has_short_clusters() && name == "h";
+        name.eq_ignore_ascii_case("help")
+            || is_help_short
+            || !matches!(self.resolve_long(name), Ok(None))
+    }
+
+    /// Check if a token is a group of short arguments like "-c" or "-xvf".
+    /// Any token starting with '-' is one, so that unknown short names are
+    /// reported rather than read as a value, except a negative number which
+    /// does not start with a short name and is not rejected by the `Arg`
+    /// expecting a value, so that it can fill a positional `Arg`.
+    fn is_short_cluster(&self, token: &str, value_expected: bool) -> bool {
+        if !self.has_short_clusters() || token.starts_with("--") {
+            return false;
+        }
+        let mut chars = token.chars();
+        match (chars.next(), chars.next(), chars.next()) {
+            (Some('-'), Some('h'), None) => true,
+            (Some('-'), Some(short), _) => {
+                value_expected || self.find_short(short).is_some() || token.parse::<f64>().is_err()
+            }
+            _ => false,
+        }
+    }
+
+    /// Find the name of the `Arg` owning the short name `short`.
+    fn find_short(&self, short: char) -> Option<String> {
+        self.named_args
+            .borrow()
+            .iter()
+            .find(|(_, arg)| !arg.is_positional() && arg.has_short(short))
+            .map(|(name, _)| String::from(name))
+    }
+
+    /// Get every name matched by "--<name>", aliases and negations included.
+    fn long_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        let named_args = self.named_args.borrow();
+        for (arg_name, arg) in named_args.iter().filter(|(_, arg)| !arg.is_positional()) {
+            let mut arg_names = vec![String::from(arg_name)];
+            arg_names.extend(arg.aliases.borrow().iter().cloned());
+            for name in arg_names {
+                if arg.negatable.get() && !arg.has_value {
+                    names.push(format!("no-{}", name));
+                }
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Replace '_' by '-' when separators are not significant.
+    fn normalize(&self, name: &str) -> String {
+        if self.separator_insensitive.get() {
+            name.replace('_', "-")
+        } else {
+            String::from(name)
+        }
+    }
+
+    /// Find the name of the `Arg` matched by "--<name>", aliases included.
+    fn find_long(&self, name: &str) -> Option<String> {
+        if let Some(arg) = self.named_args.borrow().get(name) {
+            if !arg.is_positional() {
+                return Some(String::from(name));
+            }
+        }
+        let name = self.normalize(name);
+        self.named_args
+            .borrow()
+            .iter()
+            .filter(|(_, arg)| !arg.is_positional())
+            .find(|(arg_name, arg)| {
+                self.normalize(arg_name) == name
+                    || arg.aliases.borrow().iter().any(|alias| self.normalize(alias) == name)
+            })
+            .map(|(arg_name, _)| String::from(arg_name))
+            .or_else(|| {
+                // without short prefix, short names are given as long ones
+                let mut chars = name.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(short), None) if !self.has_short_clusters() => self.find_short(short),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Resolve "--<name>" into the name of its `Arg` and whether or not it
+    /// is negated, `None` if there is no such `Arg`.
+    fn resolve_long(&self, name: &str) -> Result<Option<(String, bool)>, String> {
+        if let Some(resolved) = self.resolve_exact(name) {
+            return Ok(Some(resolved));
+        }
+        if self.abbreviations.get() {
+            let prefix = self.normalize(name);
+            let candidates: Vec<String> = self
+                .long_names()
+                .into_iter()
+                .filter(|candidate| self.normalize(candidate).starts_with(&prefix))
+                .collect();
+            let mut resolved: Vec<(String, bool)> = candidates
+                .iter()
+                .filter_map(|candidate| self.resolve_exact(candidate))
+                .collect();
+            resolved.sort();
+            resolved.dedup();
+            match resolved.len() {
+                0 => {}
+                1 => return Ok(resolved.pop()),
+                _ => {
+                    let candidates: Vec<String> = candidates
+                        .iter()
+                        .map(|candidate| format!("{}{}", self.long_prefix(), candidate))
+                        .collect();
+                    return Err(format!(
+                        "ambiguous argument {}{}, could be {}",
+                        self.long_prefix(),
+                        name,
+                        candidates.join(", ")
+                    ));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Resolve "--<name>" without abbreviation.
+    fn resolve_exact(&self, name: &str) -> Option<(String, bool)> {
+        if let Some(name) = self.find_long(name) {
+            return Some((name, false));
+        }
+        self.find_negated(name).map(|name| (name, true))
+    }
+
+    /// Build the error message of an unknown "--<name>" argument.
+    fn unknown_long(&self, name: &str) -> String {
+        let suggestions = suggestion::closest(name, self.long_names().iter());
+        format!(
+            "unknown argument {}{}{}",
+            self.long_prefix(),
+            name,
+            suggestion::did_you_mean(&self.long_prefix(), &suggestions)
+        )
+    }
+
+    /// Find the name of the negatable `Arg` matched by "--no-<name>".
+    fn find_negated(&self, name: &str) -> Option<String> {
+        let name = self.find_long(name.strip_prefix("no-")?)?;
+        match self.named_args.borrow().get(&name) {
+            Some(arg) if arg.negatable.get() && !arg.has_value => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Get the prefixes of long arguments, longest first.
+    fn long_prefixes(&self) -> Vec<String> {
+        let mut prefixes = match &*self.prefix_style.borrow() {
+            PrefixStyle::Gnu => vec![String::from("--")],
+            PrefixStyle::SingleDashLong => vec![String::from("-"), String::from("--")],
+            PrefixStyle::Slash => vec![String::from("/")],
+            PrefixStyle::Custom(prefixes) => prefixes.clone(),
+        };
+        prefixes.sort_by_key(|prefix| Reverse(prefix.len()));
+        prefixes
+    }
+
+    /// Get the prefix of long arguments used in messages.
+    fn long_prefix(&self) -> String {
+        match &*self.prefix_style.borrow() {
+            PrefixStyle::Gnu => String::from("--"),
+            PrefixStyle::SingleDashLong => String::from("-"),
+            PrefixStyle::Slash => String::from("/"),
+            PrefixStyle::Custom(prefixes) => prefixes.first().cloned().unwrap_or_default(),
+        }
+    }
+
+    /// Get the prefix of short arguments used in messages.
+    fn short_prefix(&self) -> String {
+        if self.has_short_clusters() {
+            String::from("-")
+        } else {
+            self.long_prefix()
+        }
+    }
+
+    fn has_short_clusters(&self) -> bool {
+        *self.prefix_style.borrow() == PrefixStyle::Gnu
+    }
+
+    /// Get the name of a long argument token, `None` if the token is not one.
+    /// In the slash style, a name containing '/' before any "=value" is a
+    /// path like "/tmp/file" rather than an argument.
+    fn strip_long_prefix<'t>(&self, token: &'t str) -> Option<&'t str> {
+        let is_slash = *self.prefix_style.borrow() == PrefixStyle::Slash;
+        for prefix in self.long_prefixes() {
+            if let Some(name) = token.strip_prefix(&*prefix) {
+                let is_path = is_slash && name.split('=').next().unwrap_or(name).contains('/');
+                if !name.is_empty() && !is_path {
+                    return Some(name);
+                }
+            }
+        }
+        None
+    }
+
+    fn display_name(&self, arg: &Arg) -> String {
+        arg.display_name_with(&self.long_prefix(), &self.short_prefix())
+    }
+
+    /// Get the name of the `Arg` named `name` as shown to the user,
+    /// even if there is no such `Arg`.
+    fn display_arg_name(&self, name: &str) -> String {
+        match self.named_args.borrow().get(name) {
+            Some(arg) => self.display_name(arg),
+            None => format!("{}{}", self.long_prefix(), name),
+        }
+    }
+
+    /// Join the usages of the named `Arg`s, the members of a group being
+    /// rendered together, like " (--json | --yaml)", at the place of the
+    /// first of them. An `Arg` in several groups is rendered in each one.
+    fn join_usages(&self, usages: &[(String, String)]) -> String {
+        let groups = self.groups.borrow();
+        let usage_of = |name: &String| {
+            usages
+                .iter()
+                .find(|(arg_name, _)| arg_name == name)
+                .map(|(_, usage)| usage.as_str())
+        };
+        let mut rendered = vec![false; groups.len()];
+        let mut printed: Vec<&String> = Vec::new();
+        let mut params = String::new();
+        for (name, usage) in usages {
+            for (index, group) in groups.iter().enumerate() {
+                if rendered[index] || !group.members.contains(name) {
+                    continue;
+                }
+                rendered[index] = true;
+                printed.extend(group.members.iter());
+                let members: Vec<&str> = group.members.iter().filter_map(usage_of).collect();
+                params = format!("{} ({})", params, members.join(" | "));
+            }
+            if !printed.contains(&name) {
+                printed.push(name);
+                params = format!("{} {}", params, usage);
+            }
+        }
+        params
+    }
+
+    /// Get the positional `Arg`s sorted by index.
+    fn positionals(&self) -> Vec<&'a Arg> {
+        let mut positionals: Vec<&'a Arg> = self
+            .named_args
+            .borrow()
+            .values()
+            .filter(|arg| arg.is_positional())
+            .copied()
+            .collect();
+        positionals.sort_by_key(|arg| arg.index);
+        positionals
+    }
+
+    /// Check that the positional `Arg`s can be filled unambiguously.
+    fn check_positionals(&self) -> Result<(), String> {
+        let positionals = self.positionals();
+        for pair in positionals.windows(2) {
+            if pair[0].is_multiple() {
+                return Err(format!(
+                    "Only the last positional argument can be variadic, not {} !",
+                    self.display_name(pair[0])
+                ));
+            }
+            if pair[0].index == pair[1].index {
+                return Err(format!(
+                    "Positional arguments {} and {} share the same index !",
+                    self.display_name(pair[0]),
+                    self.display_name(pair[1])
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the default values, read as one value, fit the count of
+    /// values of their `Arg`.
+    fn check_default_values(&self) -> Result<(), String> {
+        for arg in self.named_args.borrow().values() {
+            match arg.num_values.get() {
+                Some((min, _)) if min > 1 && arg.has_default_value() => {
+                    return Err(format!(
+                        "Argument {} needs at least {} values, its default value is one !",
+                        self.display_name(arg),
+                        min
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the conflicts and requirements of the `Arg`s name known
+    /// `Arg`s, like the members of a group.
+    fn check_references(&self) {
+        let named_args = self.named_args.borrow();
+        let inherited_args = self.inherited_args.borrow();
+        // global `Arg`s are checked by the parent
+        for (_, arg) in named_args.iter().filter(|(name, _)| !inherited_args.contains(name)) {
+            let conflicts = arg.conflicts.borrow();
+            let required_unless = arg.required_unless.borrow();
+            let required_if_eq = arg.required_if_eq.borrow();
+            let requirements = arg.requirements.borrow();
+            let unknown = conflicts
+                .iter()
+                .chain(required_unless.iter())
+                .chain(required_if_eq.iter().map(|(name, _)| name))
+                .chain(requirements.iter().map(|(_, name)| name))
+                .find(|name| !named_args.contains_key(*name));
+            if let Some(name) = unknown {
+                panic!("Argument \"{}\" does not exists !", name);
+            }
+        }
+    }
+
+    /// Read `arg`, split on the delimiter of the `Arg` if it has one.
+    fn read_values(&self, arg: OsString, arg_name: String) -> Result<(), String> {
+        let delimiter = match self.named_args.borrow().get(&arg_name) {
+            Some(argument) => argument.delimiter.get(),
+            None => None,
+        };
+        match (delimiter, arg.to_str()) {
+            (Some(delimiter), Some(arg)) => {
+                for value in arg.split(delimiter) {
+                    self.read_os_value(OsString::from(value), String::from(&arg_name))?;
+                }
+                Ok(())
+            }
+            _ => self.read_os_value(arg, arg_name),
+        }
+    }
+
+    /// Read `arg`, keeping it as is for `Type`s which do not need unicode.
+    fn read_os_value(&self, arg: OsString, arg_name: String) -> Result<(), String> {
+        if let Some(argument) = self.named_args.borrow().get(&arg_name) {
+            // like the values, the last raw value of a single value wins
+            let raw = arg.to_string_lossy().into_owned();
+            if argument.is_multiple() {
+                argument.raw_values.borrow_mut().push(raw);
+            } else {
+                argument.raw_values.replace(vec![raw]);
+            }
+            match argument.type_read {
+                Some(PrivateType::ReadAsPathBuf(_)) => {
+                    argument.store_value(Box::new(PathBuf::from(arg)));
+                    return Ok(());
+                }
+                Some(PrivateType::ReadAsOsString(_)) => {
+                    argument.store_value(Box::new(arg));
+                    return Ok(());
+                }
+                _ => {}
+            }
+        }
+        match arg.into_string() {
+            Ok(arg) => self.read_value(arg, arg_name),
+            Err(arg) => Err(format!(
+                "Argument value {} for {} must be valid unicode",
+                arg.to_string_lossy(),
+                arg_name
+            )),
+        }
+    }
+
+    fn read_value(&self, arg: String, arg_name: String) -> Result<(), String> {
+        if let Some(argument) = self.named_args.borrow().get(&arg_name) {
+            if argument.has_value {
+                let value: Box<dyn Any> = match &argument.type_read {
+                    Some(PrivateType::ReadAsU8(_)) => {
+                        let tmp = arg.parse::<u8>().map_err(|e| {
+                            format!("Argument value {} for {} must be u8: {}", arg, arg_name, e)
+                        })?;
+                        Box::new(tmp)
+                    }
+                    Some(PrivateType::ReadAsU16(_)) => {
+                        let tmp = arg.parse::<u16>().map_err(|e| {
                             format!("Argument value {} for {} must be u16: {}", arg, arg_name, e)
                         })?;
                         Box::new(tmp)
@@ -452,10 +2007,12 @@ impl<'a> CliArguments<'a> {
                         })?;
                         Box::new(tmp)
                     }
-                    Some(PrivateType::ReadAsString(_)) => Box::new(String::from(arg)),
+                    Some(PrivateType::ReadAsString(_)) => Box::new(arg),
+                    Some(PrivateType::ReadAsPathBuf(_)) => Box::new(PathBuf::from(arg)),
+                    Some(PrivateType::ReadAsOsString(_)) => Box::new(OsString::from(arg)),
                     None => return Err(format!("Argument {} must have a value", arg_name)),
                 };
-                argument.value.replace(Some(value));
+                argument.store_value(value);
             } else {
                 argument.value.replace(Some(Box::new(true)));
             }
@@ -463,13 +2020,251 @@ impl<'a> CliArguments<'a> {
         Ok(())
     }
 
-    fn reset_args(&self) {
-        for (_, arg) in self.named_args.iter() {
+    /// Reset the `Arg`s, except the global ones in `parent_checked`, which
+    /// keep the values read by the parent.
+    fn reset_args(&self, parent_checked: &[String]) {
+        for (name, arg) in self.named_args.borrow().iter() {
+            if parent_checked.contains(name) {
+                continue;
+            }
             arg.value.replace(None);
+            arg.values.borrow_mut().clear();
+            arg.raw_values.borrow_mut().clear();
             arg.found.set(false);
+            arg.occurrences.set(0);
+            arg.negated.set(false);
+        }
+        self.trailing_args.borrow_mut().clear();
+        self.matched_subcommand.replace(None);
+        self.external_subcommand.replace(None);
+    }
+
+    /// Make the global `Arg`s of the parent commands, as they are now,
+    /// known to this subcommand.
+    fn inherit_args(&self) {
+        let visible_args = self.visible_args();
+        let mut inherited_args = self.inherited_args.borrow_mut();
+        let mut named_args = self.named_args.borrow_mut();
+        let inherited: Vec<String> = visible_args
+            .keys()
+            .filter(|name| !named_args.contains_key(*name) || inherited_args.contains(name))
+            .cloned()
+            .collect();
+        *inherited_args = inherited;
+        *named_args = visible_args;
+    }
+
+    /// Get the named `Arg`s with the global ones of the parent commands, an
+    /// `Arg` hiding the ones of the same name in the parent commands.
+    fn visible_args(&self) -> BTreeMap<String, &'a Arg> {
+        let inherited_args = self.inherited_args.borrow();
+        let mut named_args: BTreeMap<String, &'a Arg> = self
+            .named_args
+            .borrow()
+            .iter()
+            .filter(|(name, _)| !inherited_args.contains(name))
+            .map(|(name, arg)| (String::from(name), *arg))
+            .collect();
+        let mut hidden: Vec<&String> = Vec::new();
+        for (name, arg) in self.parent_args.borrow().iter() {
+            if named_args.contains_key(name) || hidden.contains(&name) {
+                continue;
+            }
+            if arg.global.get() {
+                named_args.insert(String::from(name), arg);
+            } else {
+                hidden.push(name);
+            }
+        }
+        named_args
+    }
+
+    /// Get the named `Arg`s a subcommand may inherit, the ones of this
+    /// command first, then the ones of its parents.
+    fn lineage_args(&self) -> Vec<(String, &'a Arg)> {
+        let inherited_args = self.inherited_args.borrow();
+        let mut args: Vec<(String, &'a Arg)> = self
+            .named_args
+            .borrow()
+            .iter()
+            .filter(|(name, arg)| !arg.is_positional() && !inherited_args.contains(name))
+            .map(|(name, arg)| (String::from(name), *arg))
+            .collect();
+        args.extend(self.parent_args.borrow().iter().cloned());
+        args
+    }
+
+    /// Set the named `Arg`s of the parent commands, for this command and
+    /// its own subcommands.
+    fn set_parent_args(&self, parent_args: Vec<(String, &'a Arg)>) {
+        self.parent_args.replace(parent_args);
+        self.inherit_args();
+        let lineage_args = self.lineage_args();
+        for subcommand in self.subcommands.borrow().values() {
+            subcommand.set_parent_args(lineage_args.clone());
         }
     }
 
+    ///  Sets whether or not unknown arguments are ignored instead of
+    ///  being reported as an error, which is the default.
+    ///
+    /// # Arguments
+    /// * `allow_unknown` - Whether or not unknown arguments are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let a = Arg::with_value("threshold", Type::ReadAsU8, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    ///
+    /// let args = vec!["--treshold", "5"];
+    /// let msg = cli.parse_subset(args.clone().into_iter().map(String::from)).unwrap_err();
+    /// assert_eq!(msg, "unknown argument --treshold, did you mean --threshold?");
+    ///
+    /// cli.set_allow_unknown(true);
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    /// # }
+    /// ```
+    pub fn set_allow_unknown(&self, allow_unknown: bool) {
+        self.allow_unknown.set(allow_unknown);
+    }
+
+    ///  Sets whether or not a long argument may be abbreviated to any
+    ///  unambiguous prefix of its name, like "--thr" for "--threshold".
+    ///
+    /// # Arguments
+    /// * `abbreviations` - Whether or not prefixes are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let a = Arg::with_value("threshold", Type::ReadAsU8, false);
+    /// let b = Arg::with_value("thread", Type::ReadAsU8, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a, &b);
+    /// cli.set_abbreviations(true);
+    ///
+    /// let args = vec!["--thres", "4"];
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    /// assert_eq!(cli.get_value::<u8>("threshold"), 4);
+    ///
+    /// // "--thr" could be both
+    /// let args = vec!["--thr", "4"];
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+    /// # }
+    /// ```
+    pub fn set_abbreviations(&self, abbreviations: bool) {
+        self.abbreviations.set(abbreviations);
+    }
+
+    ///  Sets whether or not '-' and '_' are equivalent in long argument
+    ///  names, so that "--thread-count" and "--thread_count" match the same `Arg`.
+    ///
+    /// # Arguments
+    /// * `separator_insensitive` - Whether or not '-' and '_' are equivalent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let a = Arg::with_value("thread-count", Type::ReadAsU8, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    /// cli.set_separator_insensitive(true);
+    ///
+    /// let args = vec!["--thread_count", "4"];
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    /// assert_eq!(cli.get_value::<u8>("thread-count"), 4);
+    /// # }
+    /// ```
+    pub fn set_separator_insensitive(&self, separator_insensitive: bool) {
+        self.separator_insensitive.set(separator_insensitive);
+    }
+
+    ///  Sets the prefixes introducing the arguments, `PrefixStyle::Gnu`
+    ///  being the default. Parsing and usage both follow the style.
+    ///
+    /// # Arguments
+    /// * `prefix_style` - The `PrefixStyle` of the arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::{CliArguments, PrefixStyle};
+    /// # fn main() {
+    /// let a = Arg::with_value("threshold", Type::ReadAsU8, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    /// cli.set_prefix_style(PrefixStyle::SingleDashLong);
+    ///
+    /// let args = vec!["-threshold", "5"];
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    /// assert_eq!(cli.get_value::<u8>("threshold"), 5);
+    /// # }
+    /// ```
+    pub fn set_prefix_style(&self, prefix_style: PrefixStyle) {
+        self.prefix_style.replace(prefix_style);
+    }
+
+    ///  Sets whether or not "@file" arguments are replaced by the arguments
+    ///  read from `file`, one or more per line.
+    ///
+    ///  Arguments are separated by whitespace and may be quoted like in a
+    ///  shell with '...' or "...", or escaped with a backslash. A word starting
+    ///  with '#' comments out the rest of the line. A response file may include
+    ///  other ones, relative to its own directory, but not itself. Errors
+    ///  caused by an argument read from a response file are prefixed with
+    ///  its location, like "args.txt:3: ".
+    ///
+    /// # Arguments
+    /// * `response_files` - Whether or not "@file" arguments are expanded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let a = Arg::with_value("threshold", Type::ReadAsU8, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&a);
+    /// cli.set_response_files(true);
+    ///
+    /// let file_name = format!("parg_doc_args_{}.txt", std::process::id());
+    /// let path = std::env::temp_dir().join(file_name);
+    /// std::fs::write(&path, "--threshold 5 # the default threshold").unwrap();
+    ///
+    /// let args = vec![format!("@{}", path.display())];
+    /// let result = cli.parse_subset(args.into_iter());
+    /// std::fs::remove_file(&path).unwrap();
+    /// assert!(result.is_ok());
+    /// assert_eq!(cli.get_value::<u8>("threshold"), 5);
+    /// # }
+    /// ```
+    pub fn set_response_files(&self, response_files: bool) {
+        self.response_files.set(response_files);
+    }
+
     ///  Sets the cli name and description.
     ///
     /// # Arguments
@@ -496,4 +2291,337 @@ impl<'a> CliArguments<'a> {
         self.app_name.replace(String::from(app_name));
         self.description.replace(String::from(description));
     }
+
+    ///  Declare a group named `name` of which at most one member may be
+    ///  given, like "--json" and "--yaml". The help renders it as
+    ///  "(--json | --yaml)". A group with the same name is replaced.
+    ///  Panics if a member is not an `Arg` of this `CliArguments`.
+    ///
+    ///  This is a shortcut for `add_group` with `GroupRule::AtMostOne`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the group.
+    /// * `members` - The names of the `Arg`s of the group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let json = Arg::without_value("json", false);
+    /// let yaml = Arg::without_value("yaml", false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&json, &yaml);
+    /// cli.add_exclusive_group("format", &["json", "yaml"]);
+    ///
+    /// let args = vec!["--json", "--yaml"];
+    /// assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+    /// # }
+    /// ```
+    pub fn add_exclusive_group(&self, name: &str, members: &[&str]) {
+        self.add_group(name, members, GroupRule::AtMostOne);
+    }
+
+    ///  Declare a group named `name` of which as many members may be given
+    ///  as `rule` allows, like at least one of "--id", "--name" or "--tag".
+    ///  The help renders it as "(--id | --name | --tag)". A group with the
+    ///  same name is replaced.
+    ///  Panics if a member is not an `Arg` of this `CliArguments`.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the group.
+    /// * `members` - The names of the `Arg`s of the group.
+    /// * `rule` - How many members may be given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::{CliArguments, GroupRule};
+    /// # fn main() {
+    /// let id = Arg::with_value("id", Type::ReadAsU32, false);
+    /// let name = Arg::with_value("name", Type::ReadAsString, false);
+    ///
+    /// // Create the cli
+    /// let cli: CliArguments = create_cli_arguments!(&id, &name);
+    /// cli.add_group("selector", &["id", "name"], GroupRule::AtLeastOne);
+    ///
+    /// assert!(cli.parse_subset(Vec::new().into_iter()).is_err());
+    /// # }
+    /// ```
+    pub fn add_group(&self, name: &str, members: &[&str], rule: GroupRule) {
+        for member in members {
+            if !self.named_args.borrow().contains_key(*member) {
+                panic!("Argument \"{}\" does not exists !", member);
+            }
+        }
+        let mut groups = self.groups.borrow_mut();
+        groups.retain(|group| group.name != name);
+        groups.push(Group {
+            name: String::from(name),
+            members: members.iter().map(|member| String::from(*member)).collect(),
+            rule,
+        });
+    }
+
+    ///  Register `subcommand` under `name`, like "commit" in "git commit".
+    ///
+    ///  The first plain argument naming a subcommand selects it: the
+    ///  arguments before it are parsed by this `CliArguments`, the ones
+    ///  after it by the subcommand, whose help is given by
+    ///  "<app> <name> --help".
+    ///  The subcommand is named `name` in its help unless `set_info` was
+    ///  called on it.
+    ///  Global `Arg`s are accepted by the subcommand too, see
+    ///  `Arg::set_global`.
+    ///
+    /// # Arguments
+    /// * `name` - The name selecting the subcommand on the command line.
+    /// * `subcommand` - The `CliArguments` parsing the subcommand arguments.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let all = Arg::without_value("all", false);
+    /// let msg = Arg::with_value("msg", Type::ReadAsString, false);
+    /// let commit: CliArguments = create_cli_arguments!(&all, &msg);
+    /// commit.set_info("git commit", "Record changes to the repository");
+    ///
+    /// let verbose = Arg::without_value("verbose", false);
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    /// cli.add_subcommand("commit", &commit);
+    ///
+    /// let args = vec!["--verbose", "commit", "--all", "--msg", "fix"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// let (name, subcommand) = cli.get_subcommand().unwrap();
+    /// assert_eq!(name, "commit");
+    /// assert!(subcommand.exists("all"));
+    /// assert_eq!(subcommand.get_value::<String>("msg"), "fix");
+    /// # }
+    /// ```
+    pub fn add_subcommand(&self, name: &str, subcommand: &'a CliArguments<'a>) {
+        if subcommand.app_name.borrow().is_empty() {
+            subcommand.app_name.replace(String::from(name));
+        }
+        self.subcommands.borrow_mut().insert(String::from(name), subcommand);
+        subcommand.set_parent_args(self.lineage_args());
+    }
+
+    ///  Add an alias selecting the subcommand `name`, like "co" for
+    ///  "checkout". Panics if there is no such subcommand.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the subcommand.
+    /// * `alias` - The alias to add.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let checkout: CliArguments = create_cli_arguments!();
+    ///
+    /// let cli: CliArguments = create_cli_arguments!();
+    /// cli.add_subcommand("checkout", &checkout);
+    /// cli.add_subcommand_alias("checkout", "co");
+    ///
+    /// cli.parse_subset(vec![String::from("co")].into_iter()).unwrap();
+    /// assert_eq!(cli.get_subcommand().unwrap().0, "checkout");
+    /// # }
+    /// ```
+    pub fn add_subcommand_alias(&self, name: &str, alias: &str) {
+        if !self.subcommands.borrow().contains_key(name) {
+            panic!("Subcommand \"{}\" does not exists !", name);
+        }
+        self.subcommand_aliases
+            .borrow_mut()
+            .insert(String::from(alias), String::from(name));
+    }
+
+    ///  Sets whether or not a subcommand may be selected by any
+    ///  unambiguous prefix of its name or aliases, like "chec" for
+    ///  "checkout".
+    ///
+    /// # Arguments
+    /// * `infer_subcommands` - Whether or not prefixes are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let checkout: CliArguments = create_cli_arguments!();
+    /// let cherry_pick: CliArguments = create_cli_arguments!();
+    ///
+    /// let cli: CliArguments = create_cli_arguments!();
+    /// cli.add_subcommand("checkout", &checkout);
+    /// cli.add_subcommand("cherry-pick", &cherry_pick);
+    /// cli.set_infer_subcommands(true);
+    ///
+    /// cli.parse_subset(vec![String::from("chec")].into_iter()).unwrap();
+    /// assert_eq!(cli.get_subcommand().unwrap().0, "checkout");
+    ///
+    /// // "che" could be both
+    /// assert!(cli.parse_subset(vec![String::from("che")].into_iter()).is_err());
+    /// # }
+    /// ```
+    pub fn set_infer_subcommands(&self, infer_subcommands: bool) {
+        self.infer_subcommands.set(infer_subcommands);
+    }
+
+    ///  Get the subcommand selected by the last parse.
+    ///
+    /// # Returns
+    /// Return the name and the `CliArguments` of the subcommand,
+    /// holding its parsed values, or `None` if no subcommand was given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let force = Arg::without_value("force", false);
+    /// let push: CliArguments = create_cli_arguments!(&force);
+    ///
+    /// let verbose = Arg::without_value("verbose", false);
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    /// cli.add_subcommand("push", &push);
+    ///
+    /// let args = vec!["push", "--force"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// match cli.get_subcommand() {
+    ///     Some((name, push)) if name == "push" => assert!(push.exists("force")),
+    ///     _ => unreachable!(),
+    /// }
+    /// # }
+    /// ```
+    pub fn get_subcommand(&self) -> Option<(String, &'a CliArguments<'a>)> {
+        let name = self.matched_subcommand.borrow().clone()?;
+        let subcommand = self.subcommands.borrow()[&name];
+        Some((name, subcommand))
+    }
+
+    ///  Sets whether or not an unknown subcommand is accepted as an
+    ///  external one, like "cargo foo" running the "cargo-foo" plugin.
+    ///
+    ///  The first plain argument then selects a subcommand, which is
+    ///  external if not registered with `add_subcommand`. This is ignored
+    ///  when the `CliArguments` has positional `Arg`s, which would be
+    ///  ambiguous.
+    ///  The help printed by "--help", given by `generate_help`, also lists
+    ///  the plugins found in `PATH`, the executables named "<app>-<name>",
+    ///  "<app>" being the name given to `set_info` with spaces replaced
+    ///  by '-'.
+    ///
+    /// # Arguments
+    /// * `external_subcommands` - Whether or not unknown subcommands are accepted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # use std::ffi::OsString;
+    /// # fn main() {
+    /// let verbose = Arg::without_value("verbose", false);
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    /// cli.set_info("app", "An extensible app");
+    /// cli.set_external_subcommands(true);
+    ///
+    /// let args = vec!["--verbose", "deploy", "--to", "prod"];
+    /// cli.parse_subset(args.into_iter().map(String::from)).unwrap();
+    ///
+    /// let (name, args) = cli.get_external_subcommand().unwrap();
+    /// assert_eq!(name, "deploy");
+    /// assert_eq!(args, vec![OsString::from("--to"), OsString::from("prod")]);
+    /// # }
+    /// ```
+    pub fn set_external_subcommands(&self, external_subcommands: bool) {
+        self.external_subcommands.set(external_subcommands);
+    }
+
+    ///  Get the external subcommand selected by the last parse.
+    ///
+    /// # Returns
+    /// Return the name of the subcommand and the raw arguments following
+    /// it, or `None` if no external subcommand was given.
+    ///
+    /// # Example
+    /// See `set_external_subcommands`.
+    pub fn get_external_subcommand(&self) -> Option<(String, Vec<OsString>)> {
+        self.external_subcommand.borrow().clone()
+    }
+
+    ///  Run the plugin of the external subcommand selected by the last
+    ///  parse, the first executable named "<app>-<name>" found in `PATH`,
+    ///  with the arguments following the subcommand, and wait for it.
+    ///
+    /// # Returns
+    /// Return the exit status of the plugin, or an error message if no
+    /// external subcommand was given or no plugin matches it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # #[macro_use] extern crate parg;
+    /// # use parg::{Arg, Type};
+    /// # use parg::CliArguments;
+    /// # fn main() {
+    /// let verbose = Arg::without_value("verbose", false);
+    /// let cli: CliArguments = create_cli_arguments!(&verbose);
+    /// cli.set_info("app", "An extensible app");
+    /// cli.set_external_subcommands(true);
+    ///
+    /// if let Err(msg) = cli.parse() {
+    ///     eprintln!("{}", msg);
+    ///     std::process::exit(1);
+    /// }
+    /// if cli.get_external_subcommand().is_some() {
+    ///     // "app deploy --to prod" runs "app-deploy --to prod"
+    ///     match cli.run_external_subcommand() {
+    ///         Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+    ///         Err(msg) => {
+    ///             eprintln!("{}", msg);
+    ///             std::process::exit(1);
+    ///         }
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn run_external_subcommand(&self) -> Result<ExitStatus, String> {
+        let (name, args) = match self.get_external_subcommand() {
+            Some(external_subcommand) => external_subcommand,
+            None => return Err(String::from("no external subcommand given")),
+        };
+        let path = match plugin::find(&self.plugin_prefix(), &name) {
+            Some(path) => path,
+            None => return Err(self.unknown_subcommand(&name)),
+        };
+        Command::new(&path)
+            .args(args)
+            .status()
+            .map_err(|e| format!("cannot run {}: {}", path.display(), e))
+    }
+
+    /// Get the prefix of the plugin executables, like "git" for "git-foo".
+    fn plugin_prefix(&self) -> String {
+        self.app_name.borrow().replace(' ', "-")
+    }
 }
@@ -1,8 +1,13 @@
 mod arg;
 mod cli_arguments;
 mod macros;
+mod plugin;
+mod response_file;
+mod suggestion;
 
 pub use arg::Arg;
 pub use arg::Type;
 
 pub use cli_arguments::CliArguments;
+pub use cli_arguments::GroupRule;
+pub use cli_arguments::PrefixStyle;
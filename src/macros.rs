@@ -37,8 +37,22 @@
 /// let cli: CliArguments = CliArguments::new(tree);
 /// # }
 /// ```
+///
+/// Without any `Arg`, like for a subcommand taking no arguments,
+/// this gives an empty `CliArguments`.
+///
+/// ```
+/// # #[macro_use] extern crate parg;
+/// # use parg::CliArguments;
+/// # fn main() {
+/// let status: CliArguments = create_cli_arguments!();
+/// # }
+/// ```
 #[macro_export]
 macro_rules! create_cli_arguments {
+    () => {
+        CliArguments::new(std::collections::BTreeMap::new())
+    };
     ($($args:expr),+) => {
         {
             use std::collections::BTreeMap;
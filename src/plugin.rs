@@ -0,0 +1,76 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Find the executables of the directories in `PATH` named "<prefix>-<name>",
+/// giving each `name` once, with the path of its first occurrence.
+pub(crate) fn find_all(prefix: &str) -> Vec<(String, PathBuf)> {
+    let mut plugins: Vec<(String, PathBuf)> = Vec::new();
+    let prefix = format!("{}-", prefix);
+    for directory in path_directories() {
+        let entries = match fs::read_dir(&directory) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        let mut found: Vec<(String, PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let path = entry.path();
+                let name = plugin_name(&path, &prefix)?;
+                if is_executable(&path) {
+                    Some((name, path))
+                } else {
+                    None
+                }
+            })
+            .filter(|(name, _)| plugins.iter().all(|(plugin, _)| plugin != name))
+            .collect();
+        plugins.append(&mut found);
+    }
+    plugins.sort_by(|(name, _), (other, _)| name.cmp(other));
+    plugins
+}
+
+/// Find the first executable of the directories in `PATH` named
+/// "<prefix>-<name>".
+pub(crate) fn find(prefix: &str, name: &str) -> Option<PathBuf> {
+    let file_name = format!("{}-{}{}", prefix, name, env::consts::EXE_SUFFIX);
+    path_directories()
+        .into_iter()
+        .map(|directory| directory.join(&file_name))
+        .find(|path| is_executable(path))
+}
+
+fn path_directories() -> Vec<PathBuf> {
+    match env::var_os("PATH") {
+        Some(path) => env::split_paths(&path).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Get the plugin name of "<prefix><name>", without the executable suffix.
+fn plugin_name(path: &Path, prefix: &str) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name.strip_prefix(prefix)?;
+    let name = name.strip_suffix(env::consts::EXE_SUFFIX).unwrap_or(name);
+    if name.is_empty() {
+        None
+    } else {
+        Some(String::from(name))
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.is_file() && metadata.permissions().mode() & 0o111 != 0,
+        Err(_) => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
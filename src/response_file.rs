@@ -0,0 +1,164 @@
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Replace every "@file" argument by the arguments read from `file`.
+///
+/// Arguments are separated by whitespace, quoted like in a shell with
+/// '...' or "..." and escaped with '\'. A word starting with '#' comments
+/// out the rest of the line. A response file may include other ones,
+/// relative paths being resolved from the directory of the including file.
+/// Nothing is expanded after the "--" terminator.
+///
+/// Each argument comes with its location, "<file>:<line>: " for an
+/// argument read from a response file, kept to locate the errors it causes.
+pub(crate) fn expand<T>(args: T) -> Result<Vec<(OsString, String)>, String>
+where
+    T: Iterator<Item = (OsString, String)>,
+{
+    let mut expansion = Expansion {
+        args: Vec::new(),
+        included: Vec::new(),
+        terminated: false,
+    };
+    for (arg, location) in args {
+        match arg.into_string() {
+            Ok(arg) => expansion.push(arg, None, &location)?,
+            Err(arg) => expansion.args.push((arg, location)),
+        }
+    }
+    Ok(expansion.args)
+}
+
+struct Expansion {
+    args: Vec<(OsString, String)>,
+    /// The response files being read, to detect cycles.
+    included: Vec<PathBuf>,
+    terminated: bool,
+}
+
+impl Expansion {
+    fn push(&mut self, arg: String, directory: Option<&Path>, location: &str) -> Result<(), String> {
+        if self.terminated || !arg.starts_with('@') || arg.len() == 1 {
+            if arg == "--" {
+                self.terminated = true;
+            }
+            self.args.push((OsString::from(arg), String::from(location)));
+            return Ok(());
+        }
+
+        let path = match directory {
+            Some(directory) => directory.join(&arg[1..]),
+            None => PathBuf::from(&arg[1..]),
+        };
+        let read_error =
+            |e| format!("{}cannot read response file {}: {}", location, path.display(), e);
+        let canonical = fs::canonicalize(&path).map_err(read_error)?;
+        if self.included.contains(&canonical) {
+            return Err(format!(
+                "{}response file {} includes itself",
+                location,
+                path.display()
+            ));
+        }
+        let content = fs::read_to_string(&path).map_err(read_error)?;
+
+        self.included.push(canonical);
+        for (word, line) in split(&content, &path)? {
+            let location = format!("{}:{}: ", path.display(), line);
+            self.push(word, path.parent(), &location)?;
+        }
+        self.included.pop();
+        Ok(())
+    }
+}
+
+/// Split the content of a response file into words, each with its line.
+fn split(content: &str, path: &Path) -> Result<Vec<(String, usize)>, String> {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut line = 1;
+    let mut word_line = 1;
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if let Some(word) = word.take() {
+                    words.push((word, word_line));
+                }
+                if c == '\n' {
+                    line += 1;
+                }
+            }
+            '#' if word.is_none() => {
+                // comment until the end of the line
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        line += 1;
+                        break;
+                    }
+                }
+            }
+            '\'' | '"' => {
+                let quote_line = line;
+                let current = word.get_or_insert_with(|| {
+                    word_line = line;
+                    String::new()
+                });
+                loop {
+                    let next = match chars.next() {
+                        Some(next) => next,
+                        None => {
+                            return Err(format!(
+                                "{}:{}: unterminated quote {}",
+                                path.display(),
+                                quote_line,
+                                c
+                            ))
+                        }
+                    };
+                    match next {
+                        next if next == c => break,
+                        '\\' if c == '"' => match chars.next() {
+                            Some(escaped) if escaped == '"' || escaped == '\\' => {
+                                current.push(escaped)
+                            }
+                            Some('\n') => line += 1,
+                            Some(other) => {
+                                current.push('\\');
+                                current.push(other);
+                            }
+                            None => current.push('\\'),
+                        },
+                        '\n' => {
+                            line += 1;
+                            current.push(next);
+                        }
+                        _ => current.push(next),
+                    }
+                }
+            }
+            '\\' => match chars.next() {
+                // line continuation
+                Some('\n') => line += 1,
+                Some(escaped) => word
+                    .get_or_insert_with(|| {
+                        word_line = line;
+                        String::new()
+                    })
+                    .push(escaped),
+                None => word.get_or_insert_with(String::new).push('\\'),
+            },
+            _ => word
+                .get_or_insert_with(|| {
+                    word_line = line;
+                    String::new()
+                })
+                .push(c),
+        }
+    }
+    if let Some(word) = word {
+        words.push((word, word_line));
+    }
+    Ok(words)
+}
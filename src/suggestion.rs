@@ -0,0 +1,52 @@
+/// Compute the Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Get the `candidates` closest to `name`, a third of the length of
+/// `name` (at least 1) being the maximal accepted distance.
+pub(crate) fn closest<'a, T>(name: &str, candidates: T) -> Vec<String>
+where
+    T: Iterator<Item = &'a String>,
+{
+    let max_distance = (name.chars().count() / 3).max(1);
+    let mut best = max_distance + 1;
+    let mut closest = Vec::new();
+    for candidate in candidates {
+        let distance = edit_distance(name, candidate);
+        if distance > max_distance {
+            continue;
+        }
+        if distance < best {
+            best = distance;
+            closest.clear();
+        }
+        if distance == best {
+            closest.push(String::from(candidate));
+        }
+    }
+    closest
+}
+
+/// Format the " did you mean" part of an error message,
+/// empty if there is no suggestion.
+pub(crate) fn did_you_mean(prefix: &str, suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        return String::new();
+    }
+    let suggestions: Vec<String> = suggestions
+        .iter()
+        .map(|suggestion| format!("{}{}", prefix, suggestion))
+        .collect();
+    format!(", did you mean {}?", suggestions.join(" or "))
+}
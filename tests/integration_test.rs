@@ -1,6 +1,8 @@
 extern crate parg;
 use parg::create_cli_arguments;
 use parg::CliArguments;
+use parg::GroupRule;
+use parg::PrefixStyle;
 use parg::{Arg, Type};
 
 #[test]
@@ -9,7 +11,7 @@ fn integration() {
     let cli: CliArguments = create_cli_arguments!(&arg1);
 
     let status = cli.parse();
-    if let Err(_) = status {
+    if status.is_err() {
         // ...
     }
 }
@@ -27,7 +29,1012 @@ fn integration_2() {
     ];
 
     let status = cli.parse_subset(args.into_iter().skip(2));
-    if let Err(_) = status {
+    if status.is_err() {
         // ...
     }
 }
+
+#[test]
+fn short_names() {
+    let config = Arg::with_value("config", Type::ReadAsString, true);
+    config.add_short('c');
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let output = Arg::with_value("output", Type::ReadAsString, false);
+    output.add_short('o');
+    output.add_short('O');
+    let cli: CliArguments = create_cli_arguments!(&config, &verbose, &output);
+
+    let args = vec!["-v", "-c", "app.toml", "-O", "out.txt"];
+    let status = cli.parse_subset(args.into_iter().map(String::from));
+    assert!(status.is_ok());
+    assert!(cli.exists("verbose"));
+    assert_eq!(cli.get_value::<String>("config"), "app.toml");
+    assert_eq!(cli.get_value::<String>("output"), "out.txt");
+
+    let status = cli.parse_subset(vec!["-v".to_string()].into_iter());
+    let msg = status.unwrap_err();
+    assert!(msg.starts_with("Argument -c/--config is required !"));
+    assert!(msg.contains("-o, -O, --output"));
+}
+
+#[test]
+fn short_clusters() {
+    let extract = Arg::without_value("extract", false);
+    extract.add_short('x');
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let file = Arg::with_value("file", Type::ReadAsString, false);
+    file.add_short('f');
+    let jobs = Arg::with_value("jobs", Type::ReadAsU8, false);
+    jobs.add_short('j');
+    let cli: CliArguments = create_cli_arguments!(&extract, &verbose, &file, &jobs);
+
+    let args = vec!["-xvf", "archive.tar", "-j8"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert!(cli.exists("extract"));
+    assert!(cli.exists("verbose"));
+    assert_eq!(cli.get_value::<String>("file"), "archive.tar");
+    assert_eq!(cli.get_value::<u8>("jobs"), 8);
+
+    // the value taking argument ends the cluster
+    let args = vec!["-fxv"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert!(!cli.exists("extract"));
+    assert_eq!(cli.get_value::<String>("file"), "xv");
+}
+
+#[test]
+fn inline_values() {
+    let threshold = Arg::with_value("threshold", Type::ReadAsU8, false);
+    let filter = Arg::with_value("filter", Type::ReadAsString, false);
+    let verbose = Arg::without_value("verbose", false);
+    let cli: CliArguments = create_cli_arguments!(&threshold, &filter, &verbose);
+
+    let args = vec!["--threshold=5", "--filter=a=b"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_value::<u8>("threshold"), 5);
+    assert_eq!(cli.get_value::<String>("filter"), "a=b");
+
+    let args = vec!["--filter="];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_value::<String>("filter"), "");
+
+    let args = vec!["--filter=/tmp/file"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_value::<String>("filter"), "/tmp/file");
+
+    let args = vec!["--filter=a/b"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_value::<String>("filter"), "a/b");
+
+    let args = vec!["--threshold="];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+
+    let args = vec!["--verbose=yes"];
+    let msg = cli
+        .parse_subset(args.into_iter().map(String::from))
+        .unwrap_err();
+    assert!(msg.starts_with("Argument --verbose does not take a value"));
+}
+
+#[test]
+fn positionals() {
+    let input = Arg::positional("input", 0, Type::ReadAsString, true);
+    let output = Arg::positional("output", 1, Type::ReadAsU32, false);
+    output.set_variadic(0, Some(2));
+    let verbose = Arg::without_value("verbose", false);
+    let cli: CliArguments = create_cli_arguments!(&input, &output, &verbose);
+    cli.set_info("mytool", "");
+    assert!(cli.generate_usage().contains("mytool --verbose <INPUT> [OUTPUT...]"));
+
+    let args = vec!["in.txt", "--verbose", "1", "2"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_value::<String>("input"), "in.txt");
+    assert_eq!(cli.get_values::<u32>("output"), vec![1, 2]);
+    assert!(cli.exists("verbose"));
+
+    let args = vec!["in.txt", "one"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+
+    let msg = cli.parse_subset(Vec::new().into_iter()).unwrap_err();
+    assert!(msg.starts_with("Argument <INPUT> is required !"));
+
+    output.set_variadic(1, None);
+    let msg = cli
+        .parse_subset(vec!["in.txt".to_string()].into_iter())
+        .unwrap_err();
+    assert!(msg.starts_with("Argument <OUTPUT...> needs at least 1 values, got 0 !"));
+}
+
+#[test]
+fn trailing_args() {
+    let verbose = Arg::without_value("verbose", false);
+    let name = Arg::with_value("name", Type::ReadAsString, false);
+    let input = Arg::positional("input", 0, Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&verbose, &name, &input);
+
+    let args = vec!["--verbose", "--", "--name", "child", "--", "-x"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert!(cli.exists("verbose"));
+    assert!(!cli.exists("name"));
+    assert!(!cli.exists("input"));
+    assert_eq!(cli.get_trailing_args(), vec!["--name", "child", "--", "-x"]);
+
+    let args = vec!["in.txt"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert!(cli.get_trailing_args().is_empty());
+}
+
+#[test]
+fn multiple_values() {
+    let include = Arg::with_value("include", Type::ReadAsString, false);
+    include.set_multiple(true);
+    let port = Arg::with_default_value("port", Type::ReadAsU16, Box::new(80u16), false);
+    port.set_delimiter(',');
+    let cli: CliArguments = create_cli_arguments!(&include, &port);
+
+    let args = vec!["--include", "a", "--include=b", "--port", "8080,8081", "--port", "9000"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_values::<String>("include"), vec!["a", "b"]);
+    assert_eq!(cli.get_values::<u16>("port"), vec![8080, 8081, 9000]);
+
+    assert!(cli.parse_subset(Vec::new().into_iter()).is_ok());
+    assert!(cli.get_values::<String>("include").is_empty());
+    assert_eq!(cli.get_values::<u16>("port"), vec![80]);
+
+    let args = vec!["--port", "80,http"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+
+    let msg = cli
+        .parse_subset(vec!["--include".to_string()].into_iter())
+        .unwrap_err();
+    assert!(msg.starts_with("Argument --include needs a value !"));
+}
+
+#[test]
+fn single_and_multiple_values() {
+    let size = Arg::with_value("size", Type::ReadAsU8, false);
+    let jobs = Arg::with_default_value("jobs", Type::ReadAsU8, Box::new(4u8), false);
+    let cli: CliArguments = create_cli_arguments!(&size, &jobs);
+
+    assert!(cli.parse_subset(Vec::new().into_iter()).is_ok());
+    assert!(cli.get_values::<u8>("size").is_empty());
+    assert_eq!(cli.get_values::<u8>("jobs"), vec![4]);
+
+    let args = vec!["--size", "3"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_values::<u8>("size"), vec![3]);
+}
+
+#[test]
+#[should_panic(expected = "Argument \"include\" takes several values, use get_values !")]
+fn single_value_of_multiple_values() {
+    let include = Arg::with_value("include", Type::ReadAsString, false);
+    include.set_multiple(true);
+    let cli: CliArguments = create_cli_arguments!(&include);
+
+    let args = vec!["--include", "a"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    cli.get_value::<String>("include");
+}
+
+#[test]
+fn counting_flags() {
+    let verbose = Arg::with_count("verbose", false);
+    verbose.add_short('v');
+    let quiet = Arg::with_count("quiet", false);
+    quiet.add_short('q');
+    let cli: CliArguments = create_cli_arguments!(&verbose, &quiet);
+    assert!(cli.generate_usage().contains("-v, --verbose ..."));
+
+    let args = vec!["-vvv", "--verbose", "-v"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_count("verbose"), 5);
+    assert_eq!(cli.get_count("quiet"), 0);
+    assert!(!cli.exists("quiet"));
+
+    let args = vec!["-qvq"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_count("verbose"), 1);
+    assert_eq!(cli.get_count("quiet"), 2);
+}
+
+#[test]
+fn negatable_flags() {
+    let color = Arg::without_value("color", false);
+    color.set_negatable(true);
+    let cache = Arg::without_value("cache", false);
+    let cli: CliArguments = create_cli_arguments!(&color, &cache);
+    assert!(cli.generate_usage().contains("--[no-]color"));
+
+    assert!(cli.parse_subset(Vec::new().into_iter()).is_ok());
+    assert_eq!(cli.get_toggle("color"), None);
+    assert!(cli.get_toggle("color").unwrap_or(true));
+
+    let args = vec!["--no-color"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_toggle("color"), Some(false));
+    assert!(!cli.exists("color"));
+    assert_eq!(cli.get_count("color"), 0);
+
+    let args = vec!["--no-color", "--color"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_toggle("color"), Some(true));
+    assert!(cli.exists("color"));
+
+    // not negatable
+    let args = vec!["--no-cache"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_err());
+
+    let verbose = Arg::with_count("verbose", false);
+    verbose.add_short('v');
+    verbose.set_negatable(true);
+    let cli: CliArguments = create_cli_arguments!(&verbose);
+    let args = vec!["--verbose", "--verbose", "--no-verbose"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_count("verbose"), 0);
+    assert!(!cli.exists("verbose"));
+    let args = vec!["-vv", "--no-verbose", "-v"];
+    assert!(cli.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(cli.get_count("verbose"), 1);
+    assert_eq!(cli.get_toggle("verbose"), Some(true));
+}
+
+#[test]
+fn unknown_arguments() {
+    let threshold = Arg::with_value("threshold", Type::ReadAsU8, false);
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let color = Arg::without_value("color", false);
+    color.set_negatable(true);
+    let input = Arg::positional("input", 0, Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&threshold, &verbose, &color, &input);
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert_eq!(
+        parse(vec!["--treshold", "5"]).unwrap_err(),
+        "unknown argument --treshold, did you mean --threshold?"
+    );
+    assert_eq!(
+        parse(vec!["--no-colour"]).unwrap_err(),
+        "unknown argument --no-colour, did you mean --no-color?"
+    );
+    assert_eq!(parse(vec!["--input", "a"]).unwrap_err(), "unknown argument --input");
+    assert_eq!(parse(vec!["-vx"]).unwrap_err(), "unknown argument -x");
+    // unknown short names do not fill the positional
+    assert_eq!(parse(vec!["-x"]).unwrap_err(), "unknown argument -x");
+    assert_eq!(parse(vec!["-xv"]).unwrap_err(), "unknown argument -x");
+    assert!(parse(vec!["-5"]).is_ok());
+    assert_eq!(cli.get_value::<String>("input"), "-5");
+    assert_eq!(parse(vec!["a", "b"]).unwrap_err(), "unexpected argument b");
+
+    cli.set_allow_unknown(true);
+    assert!(parse(vec!["--treshold=5", "-vx", "a", "b"]).is_ok());
+    assert!(cli.exists("verbose"));
+    assert_eq!(cli.get_value::<String>("input"), "a");
+}
+
+#[test]
+fn abbreviations() {
+    let threshold = Arg::with_value("threshold", Type::ReadAsU8, false);
+    let thread = Arg::with_value("thread", Type::ReadAsU8, false);
+    let color = Arg::without_value("color", false);
+    color.set_negatable(true);
+    let cli: CliArguments = create_cli_arguments!(&threshold, &thread, &color);
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert!(parse(vec!["--thres", "5"]).is_err());
+
+    cli.set_abbreviations(true);
+    assert!(parse(vec!["--thres", "5", "--threa=2", "--no-c"]).is_ok());
+    assert_eq!(cli.get_value::<u8>("threshold"), 5);
+    assert_eq!(cli.get_value::<u8>("thread"), 2);
+    assert_eq!(cli.get_toggle("color"), Some(false));
+
+    assert_eq!(
+        parse(vec!["--thr", "5"]).unwrap_err(),
+        "ambiguous argument --thr, could be --thread, --threshold"
+    );
+}
+
+#[test]
+fn long_aliases() {
+    let out = Arg::with_value("out", Type::ReadAsString, false);
+    out.add_alias("output");
+    out.add_alias("output-file");
+    let thread_count = Arg::with_value("thread-count", Type::ReadAsU8, false);
+    let cli: CliArguments = create_cli_arguments!(&out, &thread_count);
+    assert!(cli.generate_usage().contains("aliases: --output, --output-file"));
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert!(parse(vec!["--output-file", "a.txt"]).is_ok());
+    assert_eq!(cli.get_value::<String>("out"), "a.txt");
+    assert!(parse(vec!["--thread_count", "4"]).is_err());
+
+    cli.set_separator_insensitive(true);
+    assert!(parse(vec!["--thread_count", "4", "--output_file=b.txt"]).is_ok());
+    assert_eq!(cli.get_value::<u8>("thread-count"), 4);
+    assert_eq!(cli.get_value::<String>("out"), "b.txt");
+
+    // all the candidates are the same argument
+    cli.set_abbreviations(true);
+    assert!(parse(vec!["--outp", "c.txt"]).is_ok());
+    assert_eq!(cli.get_value::<String>("out"), "c.txt");
+}
+
+#[test]
+fn optional_values() {
+    let always = Box::new(String::from("always"));
+    let color = Arg::with_optional_value("color", Type::ReadAsString, always, false);
+    color.add_short('c');
+    let input = Arg::positional("input", 0, Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&color, &input);
+    assert!(cli.generate_usage().contains("--color[=<value>]"));
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert!(parse(vec!["--color", "in.txt"]).is_ok());
+    assert_eq!(cli.get_value::<String>("color"), "always");
+    assert_eq!(cli.get_value::<String>("input"), "in.txt");
+
+    assert!(parse(vec!["--color=never", "in.txt"]).is_ok());
+    assert_eq!(cli.get_value::<String>("color"), "never");
+
+    assert!(parse(vec!["-cauto"]).is_ok());
+    assert_eq!(cli.get_value::<String>("color"), "auto");
+
+    assert!(parse(vec!["-c"]).is_ok());
+    assert_eq!(cli.get_value::<String>("color"), "always");
+    assert!(!cli.exists("input"));
+}
+
+#[test]
+fn fixed_arity() {
+    let point = Arg::with_value("point", Type::ReadAsI32, false);
+    point.set_num_values(2, 2);
+    let range = Arg::with_value("range", Type::ReadAsU8, false);
+    range.set_num_values(1, 2);
+    range.set_multiple(true);
+    let input = Arg::positional("input", 0, Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&point, &range, &input);
+    let usage = cli.generate_usage();
+    assert!(usage.contains("--point <value> <value>"));
+    assert!(usage.contains("--range <value> [<value>]..."));
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert!(parse(vec!["--point", "3", "4", "in.txt"]).is_ok());
+    assert_eq!(cli.get_values::<i32>("point"), vec![3, 4]);
+    assert_eq!(cli.get_array::<i32, 2>("point"), [3, 4]);
+    assert_eq!(cli.get_value::<String>("input"), "in.txt");
+
+    assert!(parse(vec!["--point=1", "2", "--point", "5", "6"]).is_ok());
+    assert_eq!(cli.get_array::<i32, 2>("point"), [5, 6]);
+
+    assert!(parse(vec!["--range", "10", "--range", "20", "30", "in.txt"]).is_ok());
+    assert_eq!(cli.get_values::<u8>("range"), vec![10, 20, 30]);
+
+    let msg = parse(vec!["--point", "3"]).unwrap_err();
+    assert!(msg.starts_with("Argument --point needs at least 2 values, got 1 !"));
+    assert!(parse(vec!["--point", "3", "four"]).is_err());
+
+    // a default value is a single value
+    let origin = Arg::with_default_value("origin", Type::ReadAsI32, Box::new(1i32), false);
+    origin.set_num_values(2, 2);
+    let cli: CliArguments = create_cli_arguments!(&origin);
+    let msg = cli.parse_subset(Vec::new().into_iter()).unwrap_err();
+    assert_eq!(msg, "Argument --origin needs at least 2 values, its default value is one !");
+}
+
+#[test]
+fn negative_values() {
+    let i8_arg = Arg::with_value("i8", Type::ReadAsI8, false);
+    let i16_arg = Arg::with_value("i16", Type::ReadAsI16, false);
+    let i32_arg = Arg::with_value("i32", Type::ReadAsI32, false);
+    let i64_arg = Arg::with_value("i64", Type::ReadAsI64, false);
+    let i128_arg = Arg::with_value("i128", Type::ReadAsI128, false);
+    let isize_arg = Arg::with_value("isize", Type::ReadAsIsize, false);
+    let f32_arg = Arg::with_value("f32", Type::ReadAsF32, false);
+    let f64_arg = Arg::with_value("f64", Type::ReadAsF64, false);
+    f64_arg.add_short('t');
+    let range = Arg::with_value("range", Type::ReadAsI32, false);
+    range.set_num_values(2, 2);
+    // short names which could be mistaken for the values
+    let one = Arg::without_value("one", false);
+    one.add_short('1');
+    let five = Arg::without_value("five", false);
+    five.add_short('5');
+    let cli: CliArguments = create_cli_arguments!(
+        &i8_arg, &i16_arg, &i32_arg, &i64_arg, &i128_arg, &isize_arg, &f32_arg, &f64_arg,
+        &range, &one, &five
+    );
+
+    let args = vec![
+        "--i8", "-5", "--i16=-16", "--i32", "-1", "--i64", "-64", "--i128", "-128",
+        "--isize", "-1", "--f32", "-3.5", "-t", "-1e3", "--range", "-10", "-5", "-5",
+    ];
+    let status = cli.parse_subset(args.into_iter().map(String::from));
+    assert!(status.is_ok(), "{:?}", status);
+    assert_eq!(cli.get_value::<i8>("i8"), -5);
+    assert_eq!(cli.get_value::<i16>("i16"), -16);
+    assert_eq!(cli.get_value::<i32>("i32"), -1);
+    assert_eq!(cli.get_value::<i64>("i64"), -64);
+    assert_eq!(cli.get_value::<i128>("i128"), -128);
+    assert_eq!(cli.get_value::<isize>("isize"), -1);
+    assert_eq!(cli.get_value::<f32>("f32"), -3.5);
+    assert_eq!(cli.get_value::<f64>("f64"), -1000.0);
+    assert_eq!(cli.get_values::<i32>("range"), vec![-10, -5]);
+    assert!(!cli.exists("one"));
+    assert!(cli.exists("five"));
+
+    // "-1e3" is no i32, so short names
+    let args = vec!["--i32", "-1e3"];
+    let msg = cli.parse_subset(args.into_iter().map(String::from)).unwrap_err();
+    assert_eq!(msg, "unknown argument -e");
+
+    // unsigned values are never negative
+    let u8_arg = Arg::with_value("u8", Type::ReadAsU8, false);
+    let cli: CliArguments = create_cli_arguments!(&u8_arg, &one);
+    let args = vec!["--u8", "-1"];
+    let msg = cli.parse_subset(args.into_iter().map(String::from)).unwrap_err();
+    assert!(msg.starts_with("Argument --u8 needs a value !"));
+}
+
+#[test]
+fn hyphen_values() {
+    let pattern = Arg::with_value("pattern", Type::ReadAsString, false);
+    let extract = Arg::without_value("extract", false);
+    extract.add_short('x');
+    let cli: CliArguments = create_cli_arguments!(&pattern, &extract);
+
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    assert!(parse(vec!["--pattern", "-x"]).is_err());
+    // "-y" is not a known short name, and still not a value
+    assert_eq!(parse(vec!["--pattern", "-y"]).unwrap_err(), "unknown argument -y");
+    assert_eq!(parse(vec!["--pattern", "-5"]).unwrap_err(), "unknown argument -5");
+
+    pattern.set_allow_hyphen_values(true);
+    assert!(parse(vec!["--pattern", "-x"]).is_ok());
+    assert_eq!(cli.get_value::<String>("pattern"), "-x");
+    assert!(!cli.exists("extract"));
+
+    assert!(parse(vec!["--pattern", "--extract", "-x"]).is_ok());
+    assert_eq!(cli.get_value::<String>("pattern"), "--extract");
+    assert!(cli.exists("extract"));
+}
+
+#[test]
+fn prefix_styles() {
+    let threshold = Arg::with_value("threshold", Type::ReadAsI32, false);
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let input = Arg::positional("input", 0, Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&threshold, &verbose, &input);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    cli.set_prefix_style(PrefixStyle::SingleDashLong);
+    assert!(parse(vec!["-threshold", "-5", "-v", "--verbose"]).is_ok());
+    assert_eq!(cli.get_value::<i32>("threshold"), -5);
+    assert!(cli.exists("verbose"));
+    assert!(cli.generate_usage().contains("-v, -verbose"));
+    assert!(parse(vec!["-5"]).is_ok());
+    assert_eq!(cli.get_value::<String>("input"), "-5");
+    assert_eq!(
+        parse(vec!["-treshold", "5"]).unwrap_err(),
+        "unknown argument -treshold, did you mean -threshold?"
+    );
+
+    cli.set_prefix_style(PrefixStyle::Slash);
+    assert!(parse(vec!["/threshold=3", "/v", "/tmp/input.txt"]).is_ok());
+    assert_eq!(cli.get_value::<i32>("threshold"), 3);
+    assert!(cli.exists("verbose"));
+    assert_eq!(cli.get_value::<String>("input"), "/tmp/input.txt");
+    assert!(cli.generate_usage().contains(" /threshold <value>"));
+    let msg = parse(vec!["/threshold"]).unwrap_err();
+    assert!(msg.starts_with("Argument /threshold needs a value !"));
+    let out = Arg::with_value("out", Type::ReadAsString, false);
+    let slash_cli: CliArguments = create_cli_arguments!(&out, &verbose);
+    slash_cli.set_prefix_style(PrefixStyle::Slash);
+    let slash_parse =
+        |args: Vec<&str>| slash_cli.parse_subset(args.into_iter().map(String::from));
+    assert!(slash_parse(vec!["/out", "/home"]).is_ok());
+    assert_eq!(slash_cli.get_value::<String>("out"), "/home");
+    assert!(slash_parse(vec!["/out", "/verbose"]).is_err());
+    out.set_allow_hyphen_values(true);
+    assert!(slash_parse(vec!["/out", "/verbose"]).is_ok());
+    assert_eq!(slash_cli.get_value::<String>("out"), "/verbose");
+    assert!(!slash_cli.exists("verbose"));
+
+    cli.set_prefix_style(PrefixStyle::Custom(vec![String::from("+")]));
+    assert!(parse(vec!["+verbose", "--verbose"]).is_ok());
+    assert!(cli.exists("verbose"));
+    assert_eq!(cli.get_value::<String>("input"), "--verbose");
+
+    cli.set_prefix_style(PrefixStyle::Gnu);
+    assert!(parse(vec!["--threshold", "7", "-v"]).is_ok());
+    assert_eq!(cli.get_value::<i32>("threshold"), 7);
+}
+
+#[test]
+fn response_files() {
+    let dir = std::env::temp_dir().join(format!("parg_response_files_{}", std::process::id()));
+    std::fs::create_dir_all(dir.join("nested")).unwrap();
+    let main = dir.join("main.txt");
+    std::fs::write(
+        &main,
+        "# build options\n--name 'two words' --msg \"say \\\"hi\\\"\"\n@nested/inner.txt\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("nested").join("inner.txt"), "--verbose # nested\n").unwrap();
+    std::fs::write(dir.join("cycle.txt"), "--verbose\n@cycle.txt\n").unwrap();
+    std::fs::write(dir.join("quote.txt"), "--verbose\n\n--name 'oops\n").unwrap();
+    std::fs::write(dir.join("value.txt"), "--verbose\n--count x\n").unwrap();
+    std::fs::write(dir.join("unknown.txt"), "\n--bogus\n").unwrap();
+
+    let name = Arg::with_value("name", Type::ReadAsString, false);
+    let msg = Arg::with_value("msg", Type::ReadAsString, false);
+    let verbose = Arg::without_value("verbose", false);
+    let count = Arg::with_value("count", Type::ReadAsU8, false);
+    let cli: CliArguments = create_cli_arguments!(&name, &msg, &verbose, &count);
+    let parse = |args: Vec<String>| cli.parse_subset(args.into_iter());
+    let at = |file: &str| format!("@{}", dir.join(file).display());
+
+    assert!(parse(vec![at("main.txt")]).is_err());
+
+    cli.set_response_files(true);
+    assert!(parse(vec![at("main.txt")]).is_ok());
+    assert_eq!(cli.get_value::<String>("name"), "two words");
+    assert_eq!(cli.get_value::<String>("msg"), "say \"hi\"");
+    assert!(cli.exists("verbose"));
+
+    assert!(parse(vec![String::from("--"), at("main.txt")]).is_ok());
+    assert_eq!(cli.get_trailing_args(), vec![at("main.txt")]);
+
+    let msg = parse(vec![at("cycle.txt")]).unwrap_err();
+    assert!(msg.contains("cycle.txt:2: response file "));
+    assert!(msg.ends_with("cycle.txt includes itself"));
+
+    let msg = parse(vec![at("quote.txt")]).unwrap_err();
+    assert!(msg.ends_with("quote.txt:3: unterminated quote '"));
+
+    let msg = parse(vec![at("value.txt")]).unwrap_err();
+    assert!(msg.contains("value.txt:2: Argument value x for count must be u8"));
+
+    let msg = parse(vec![at("unknown.txt")]).unwrap_err();
+    assert!(msg.contains("unknown.txt:2: unknown argument --bogus"));
+
+    let msg = parse(vec![String::from("--count"), String::from("x")]).unwrap_err();
+    assert!(msg.starts_with("Argument value x for count must be u8"));
+
+    let msg = parse(vec![at("missing.txt")]).unwrap_err();
+    assert!(msg.starts_with("cannot read response file"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn os_string_values() {
+    use std::ffi::{OsStr, OsString};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::PathBuf;
+
+    let raw = OsStr::from_bytes(b"caf\xe9").to_os_string();
+    let mut attached = OsString::from("--out=");
+    attached.push(&raw);
+
+    let out = Arg::with_value("out", Type::ReadAsPathBuf, false);
+    out.add_short('o');
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let name = Arg::with_value("name", Type::ReadAsOsString, false);
+    let count = Arg::with_value("count", Type::ReadAsU8, false);
+    let input = Arg::positional("input", 0, Type::ReadAsPathBuf, false);
+    let cli: CliArguments = create_cli_arguments!(&out, &verbose, &name, &count, &input);
+
+    let args = vec![
+        attached,
+        OsString::from("--name"),
+        raw.clone(),
+        raw.clone(),
+        OsString::from("--"),
+        raw.clone(),
+    ];
+    assert!(cli.parse_subset_os(args.into_iter()).is_ok());
+    assert_eq!(cli.get_value::<PathBuf>("out"), PathBuf::from(&raw));
+    assert_eq!(cli.get_value::<OsString>("name"), raw);
+    assert_eq!(cli.get_value::<PathBuf>("input"), PathBuf::from(&raw));
+    assert_eq!(cli.get_trailing_args_os(), vec![raw.clone()]);
+
+    // "-o<bytes>" and "-vo<bytes>" split like "-o" "<bytes>"
+    let mut short = OsString::from("-o");
+    short.push(&raw);
+    assert!(cli.parse_subset_os(vec![short].into_iter()).is_ok());
+    assert_eq!(cli.get_value::<PathBuf>("out"), PathBuf::from(&raw));
+    let mut cluster = OsString::from("-vo");
+    cluster.push(&raw);
+    assert!(cli.parse_subset_os(vec![cluster].into_iter()).is_ok());
+    assert_eq!(cli.get_value::<PathBuf>("out"), PathBuf::from(&raw));
+    assert!(cli.exists("verbose"));
+
+    // an unknown short name does not fill the positional
+    let mut unknown = OsString::from("-x");
+    unknown.push(&raw);
+    let msg = cli.parse_subset_os(vec![unknown].into_iter()).unwrap_err();
+    assert_eq!(msg, "unknown argument -x");
+
+    let args = vec![OsString::from("--count"), raw.clone()];
+    let msg = cli.parse_subset_os(args.into_iter()).unwrap_err();
+    assert_eq!(msg, "Argument value caf\u{fffd} for count must be valid unicode");
+
+    let args = vec!["--out", "/tmp/a b", "--name", "plain"];
+    assert!(cli.parse_subset_os(args.into_iter().map(OsString::from)).is_ok());
+    assert_eq!(cli.get_value::<PathBuf>("out"), PathBuf::from("/tmp/a b"));
+    assert_eq!(cli.get_value::<OsString>("name"), OsString::from("plain"));
+}
+
+#[test]
+fn subcommands() {
+    let all = Arg::without_value("all", false);
+    all.add_short('a');
+    let msg = Arg::with_value("msg", Type::ReadAsString, true);
+    msg.add_short('m');
+    let commit: CliArguments = create_cli_arguments!(&all, &msg);
+    commit.set_info("git commit", "Record changes to the repository");
+    let status: CliArguments = create_cli_arguments!();
+
+    let verbose = Arg::without_value("verbose", false);
+    verbose.add_short('v');
+    let cli: CliArguments = create_cli_arguments!(&verbose);
+    cli.set_info("git", "The stupid content tracker");
+    cli.add_subcommand("commit", &commit);
+    cli.add_subcommand("status", &status);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["-v", "commit", "-am", "fix bug"]).is_ok());
+    assert!(cli.exists("verbose"));
+    let (name, subcommand) = cli.get_subcommand().unwrap();
+    assert_eq!(name, "commit");
+    assert!(subcommand.exists("all"));
+    assert_eq!(subcommand.get_value::<String>("msg"), "fix bug");
+
+    // arguments after the subcommand belong to it
+    assert!(parse(vec!["status", "-v"]).is_err());
+    assert!(parse(vec!["status"]).is_ok());
+    assert_eq!(cli.get_subcommand().unwrap().0, "status");
+    assert!(!cli.exists("verbose"));
+
+    assert!(parse(vec!["-v"]).is_ok());
+    assert!(cli.get_subcommand().is_none());
+
+    let msg = parse(vec!["commit", "-a"]).unwrap_err();
+    assert!(msg.starts_with("Argument -m/--msg is required !"));
+    assert!(msg.contains("Usage:\ngit commit --all"));
+
+    assert_eq!(parse(vec!["stash"]).unwrap_err(), "unknown subcommand stash");
+
+    let usage = cli.generate_usage();
+    assert!(usage.contains("Usage:\ngit --verbose <command>\n"));
+    assert!(usage.contains("Commands:\ncommit             Record changes to the repository\n"));
+    assert!(usage.contains("status"));
+    assert!(usage.contains("See 'git <command> --help'"));
+}
+
+#[test]
+fn global_arguments() {
+    let release = Arg::without_value("release", false);
+    let target = Arg::with_value("target", Type::ReadAsString, false);
+    let build: CliArguments = create_cli_arguments!(&release, &target);
+    let test: CliArguments = create_cli_arguments!();
+    build.add_subcommand("test", &test);
+
+    let verbose = Arg::with_count("verbose", false);
+    verbose.add_short('v');
+    verbose.set_global(true);
+    let default = Box::new(String::from("app.toml"));
+    let config = Arg::with_default_value("config", Type::ReadAsString, default, false);
+    config.set_global(true);
+    let local = Arg::without_value("local", false);
+    let cli: CliArguments = create_cli_arguments!(&verbose, &config, &local);
+    cli.add_subcommand("build", &build);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    // known before any parse
+    assert!(build.generate_usage().contains("-v, --verbose"));
+    assert!(test.generate_usage().contains("--config <value>"));
+
+    assert!(parse(vec!["--verbose", "build", "--release"]).is_ok());
+    assert_eq!(cli.get_count("verbose"), 1);
+    assert_eq!(build.get_count("verbose"), 1);
+    assert_eq!(build.get_value::<String>("config"), "app.toml");
+
+    // counts accumulate and the last value wins across levels
+    let args = vec!["-v", "--config", "a.toml", "build", "-v", "test", "-v", "--config", "b.toml"];
+    assert!(parse(args).is_ok());
+    assert_eq!(cli.get_count("verbose"), 3);
+    assert_eq!(test.get_count("verbose"), 3);
+    assert_eq!(cli.get_value::<String>("config"), "b.toml");
+    assert_eq!(build.get_value::<String>("config"), "b.toml");
+    assert!(test.exists("config"));
+
+    // only global arguments are propagated
+    assert!(parse(vec!["build", "--local"]).is_err());
+    assert!(parse(vec!["build", "test", "--release"]).is_err());
+    local.set_global(true);
+    assert!(test.generate_usage().contains("--local"));
+    assert!(parse(vec!["build", "test", "--local"]).is_ok());
+    local.set_global(false);
+
+    assert!(build.generate_usage().contains("--config <value>"));
+
+    // parsed on its own, a subcommand resets its global arguments too
+    assert!(parse(vec!["-vv", "build"]).is_ok());
+    let args = vec!["--release"];
+    assert!(build.parse_subset(args.into_iter().map(String::from)).is_ok());
+    assert_eq!(build.get_count("verbose"), 0);
+    assert!(!cli.exists("verbose"));
+    assert_eq!(build.get_value::<String>("config"), "app.toml");
+}
+
+#[cfg(unix)]
+#[test]
+fn external_subcommands() {
+    use std::ffi::OsString;
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("parg_plugins_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let plugin = dir.join("pargtool-hello");
+    std::fs::write(
+        &plugin,
+        "#!/bin/sh\nprintf '%s\\n' \"$@\" > \"$(dirname \"$0\")/out.txt\"\nexit 3\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&plugin, std::fs::Permissions::from_mode(0o755)).unwrap();
+    std::fs::write(dir.join("pargtool-readme"), "not executable").unwrap();
+    let old_path = std::env::var_os("PATH");
+    let mut path = OsString::from(&dir);
+    if let Some(old_path) = &old_path {
+        path.push(":");
+        path.push(old_path);
+    }
+    std::env::set_var("PATH", path);
+
+    let verbose = Arg::without_value("verbose", false);
+    let build: CliArguments = create_cli_arguments!();
+    build.set_info("pargtool build", "Build the project");
+    let cli: CliArguments = create_cli_arguments!(&verbose);
+    cli.set_info("pargtool", "A tool with plugins");
+    cli.add_subcommand("build", &build);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert_eq!(parse(vec!["hello"]).unwrap_err(), "unknown subcommand hello");
+
+    cli.set_external_subcommands(true);
+    assert!(parse(vec!["build"]).is_ok());
+    assert!(cli.get_external_subcommand().is_none());
+
+    assert!(parse(vec!["--verbose", "hello", "--name", "world"]).is_ok());
+    assert!(cli.exists("verbose"));
+    assert!(cli.get_subcommand().is_none());
+    let (name, args) = cli.get_external_subcommand().unwrap();
+    assert_eq!(name, "hello");
+    assert_eq!(args, vec![OsString::from("--name"), OsString::from("world")]);
+
+    let status = cli.run_external_subcommand().unwrap();
+    assert_eq!(status.code(), Some(3));
+    assert_eq!(std::fs::read_to_string(dir.join("out.txt")).unwrap(), "--name\nworld\n");
+
+    let help = cli.generate_help();
+    assert!(help.contains("build              Build the project\n"));
+    assert!(help.contains(&format!("hello              plugin {}\n", plugin.display())));
+    assert!(!help.contains("readme"));
+    let usage = cli.generate_usage();
+    assert!(usage.contains("build              Build the project\n"));
+    assert!(!usage.contains("hello"));
+
+    assert!(parse(vec!["bye"]).is_ok());
+    assert_eq!(cli.run_external_subcommand().unwrap_err(), "unknown subcommand bye");
+    let msg = "unknown subcommand buil, did you mean build?";
+    assert!(parse(vec!["buil"]).is_ok());
+    assert_eq!(cli.run_external_subcommand().unwrap_err(), msg);
+    let msg = "unknown subcommand helo, did you mean hello?";
+    assert!(parse(vec!["helo"]).is_ok());
+    assert_eq!(cli.run_external_subcommand().unwrap_err(), msg);
+
+    match old_path {
+        Some(old_path) => std::env::set_var("PATH", old_path),
+        None => std::env::remove_var("PATH"),
+    }
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn subcommand_aliases() {
+    let checkout: CliArguments = create_cli_arguments!();
+    checkout.set_info("app checkout", "Switch branches");
+    let cherry_pick: CliArguments = create_cli_arguments!();
+    let status: CliArguments = create_cli_arguments!();
+    let cli: CliArguments = create_cli_arguments!();
+    cli.set_info("app", "");
+    cli.add_subcommand("checkout", &checkout);
+    cli.add_subcommand("cherry-pick", &cherry_pick);
+    cli.add_subcommand("status", &status);
+    cli.add_subcommand_alias("checkout", "co");
+    cli.add_subcommand_alias("status", "st");
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+    let matched = || cli.get_subcommand().unwrap().0;
+
+    assert!(parse(vec!["co"]).is_ok());
+    assert_eq!(matched(), "checkout");
+    assert_eq!(parse(vec!["chec"]).unwrap_err(), "unknown subcommand chec");
+    assert_eq!(
+        parse(vec!["statsu"]).unwrap_err(),
+        "unknown subcommand statsu, did you mean status?"
+    );
+    assert_eq!(parse(vec!["cp"]).unwrap_err(), "unknown subcommand cp, did you mean co?");
+
+    cli.set_infer_subcommands(true);
+    assert!(parse(vec!["chec"]).is_ok());
+    assert_eq!(matched(), "checkout");
+    assert!(parse(vec!["sta"]).is_ok());
+    assert_eq!(matched(), "status");
+    // both prefixes resolve to "status"
+    assert!(parse(vec!["s"]).is_ok());
+    assert_eq!(matched(), "status");
+    assert_eq!(
+        parse(vec!["che"]).unwrap_err(),
+        "ambiguous subcommand che, could be checkout, cherry-pick"
+    );
+
+    let usage = cli.generate_usage();
+    assert!(usage.contains("checkout           Switch branches\n    aliases: co\n"));
+    assert!(usage.contains("    aliases: st\n"));
+}
+
+#[test]
+fn conflicting_arguments() {
+    let quiet = Arg::without_value("quiet", false);
+    quiet.add_short('q');
+    quiet.add_conflict("verbose");
+    let verbose = Arg::with_count("verbose", false);
+    verbose.add_short('v');
+    let json = Arg::without_value("json", false);
+    let yaml = Arg::without_value("yaml", false);
+    let csv = Arg::with_value("csv", Type::ReadAsString, false);
+    let color = Arg::without_value("color", false);
+    color.set_negatable(true);
+    color.add_conflict("quiet");
+    let cli: CliArguments = create_cli_arguments!(&quiet, &verbose, &json, &yaml, &csv, &color);
+    cli.set_info("report", "");
+    cli.add_exclusive_group("format", &["yaml", "json", "csv"]);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["-q", "--json"]).is_ok());
+    assert!(parse(vec!["-vv", "--yaml"]).is_ok());
+    assert!(parse(vec!["--no-color", "-q"]).is_ok());
+
+    let msg = parse(vec!["-v", "-q"]).unwrap_err();
+    assert!(msg.starts_with("Argument -q/--quiet cannot be used with -v/--verbose !\n"));
+    let msg = parse(vec!["--color", "-q"]).unwrap_err();
+    assert!(msg.starts_with("Argument --color cannot be used with -q/--quiet !\n"));
+    let msg = parse(vec!["--yaml", "--csv", "a.csv"]).unwrap_err();
+    assert!(msg.starts_with("Argument --yaml cannot be used with --csv !\n"));
+
+    let usage = cli.generate_usage();
+    let params = "report --[no-]color (--yaml | --json | --csv <value>) --quiet --verbose...\n";
+    assert!(usage.contains(params));
+}
+
+#[test]
+fn argument_requirements() {
+    let key = Arg::with_value("key", Type::ReadAsString, false);
+    key.add_requirement("cert");
+    let cert = Arg::with_value("cert", Type::ReadAsString, false);
+    let tls_mode = Arg::with_value("tls-mode", Type::ReadAsString, false);
+    tls_mode.add_short('t');
+    tls_mode.add_requirement_if("mutual", "ca-file");
+    let ca_file = Arg::with_value("ca-file", Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&key, &cert, &tls_mode, &ca_file);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["--cert", "c.pem"]).is_ok());
+    assert!(parse(vec!["--key", "k.pem", "--cert", "c.pem"]).is_ok());
+    let msg = parse(vec!["--key", "k.pem"]).unwrap_err();
+    assert!(msg.starts_with("Argument --key requires --cert !\n"));
+
+    assert!(parse(vec!["--tls-mode", "server"]).is_ok());
+    assert!(parse(vec!["-t", "mutual", "--ca-file", "ca.pem"]).is_ok());
+    let msg = parse(vec!["--tls-mode=mutual"]).unwrap_err();
+    assert!(msg.starts_with("Argument -t/--tls-mode=mutual requires --ca-file !\n"));
+    assert!(parse(vec!["--tls-mode", "mutual", "--tls-mode", "server"]).is_ok());
+    assert_eq!(cli.get_value::<String>("tls-mode"), "server");
+}
+
+#[test]
+#[should_panic(expected = "Argument \"verbos\" does not exists !")]
+fn unknown_conflict() {
+    let quiet = Arg::without_value("quiet", false);
+    quiet.add_conflict("verbos");
+    let verbose = Arg::without_value("verbose", false);
+    let cli: CliArguments = create_cli_arguments!(&quiet, &verbose);
+
+    let _ = cli.parse_subset(vec![String::from("--verbose")].into_iter());
+}
+
+#[test]
+fn conditionally_required_arguments() {
+    let output = Arg::with_value("output", Type::ReadAsString, false);
+    output.add_short('o');
+    output.add_required_unless("stdout");
+    let stdout = Arg::without_value("stdout", false);
+    let auth = Arg::with_value("auth", Type::ReadAsString, false);
+    let password = Arg::with_value("password", Type::ReadAsString, false);
+    password.add_required_if_eq("auth", "basic");
+    let cli: CliArguments = create_cli_arguments!(&output, &stdout, &auth, &password);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["-o", "out.txt"]).is_ok());
+    assert!(parse(vec!["--stdout"]).is_ok());
+    let msg = parse(vec![]).unwrap_err();
+    assert!(msg.starts_with("Argument -o/--output is required unless --stdout is given !\n"));
+
+    assert!(parse(vec!["--stdout", "--auth", "token"]).is_ok());
+    assert!(parse(vec!["--stdout", "--auth", "basic", "--password", "secret"]).is_ok());
+    let msg = parse(vec!["--stdout", "--auth=basic"]).unwrap_err();
+    assert!(msg.starts_with("Argument --password is required when --auth=basic !\n"));
+    assert!(parse(vec!["--stdout", "--auth", "basic", "--auth", "token"]).is_ok());
+
+    let usage = cli.generate_usage();
+    assert!(usage.contains("\n    required unless --stdout is given\n"));
+    assert!(usage.contains("\n    required when --auth=basic\n"));
+}
+
+#[test]
+fn group_rules() {
+    let id = Arg::with_value("id", Type::ReadAsU32, false);
+    let name = Arg::with_value("name", Type::ReadAsString, false);
+    name.add_short('n');
+    let tag = Arg::with_value("tag", Type::ReadAsString, false);
+    let create = Arg::without_value("create", false);
+    let delete = Arg::without_value("delete", false);
+    let cli: CliArguments = create_cli_arguments!(&id, &name, &tag, &create, &delete);
+    cli.add_group("selector", &["id", "name", "tag"], GroupRule::AtLeastOne);
+    cli.add_group("action", &["create", "delete"], GroupRule::ExactlyOne);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["--id", "1", "--create"]).is_ok());
+    assert!(parse(vec!["--id", "1", "-n", "x", "--tag", "y", "--delete"]).is_ok());
+
+    let msg = parse(vec!["--create"]).unwrap_err();
+    assert!(msg.starts_with("One of --id, -n/--name, --tag is required !\n"));
+    let msg = parse(vec!["--tag", "y"]).unwrap_err();
+    assert!(msg.starts_with("One of --create, --delete is required !\n"));
+    let msg = parse(vec!["--tag", "y", "--create", "--delete"]).unwrap_err();
+    assert!(msg.starts_with(
+        "Argument --create cannot be used with --delete, \
+         exactly one of --create, --delete is required !\n"
+    ));
+
+    let usage = cli.generate_usage();
+    assert!(usage.contains(" (--create | --delete) (--id <value> | --name <value> | --tag <value>)\n"));
+}
+
+#[test]
+fn overlapping_groups() {
+    let json = Arg::without_value("json", false);
+    let yaml = Arg::without_value("yaml", false);
+    let zfile = Arg::with_value("zfile", Type::ReadAsString, false);
+    let cli: CliArguments = create_cli_arguments!(&json, &yaml, &zfile);
+    cli.set_info("app", "");
+    cli.add_exclusive_group("format", &["json", "yaml"]);
+    cli.add_group("output", &["json", "zfile"], GroupRule::AtLeastOne);
+    let parse = |args: Vec<&str>| cli.parse_subset(args.into_iter().map(String::from));
+
+    assert!(parse(vec!["--zfile", "z", "--yaml"]).is_ok());
+    assert!(parse(vec!["--json"]).is_ok());
+    assert!(parse(vec!["--json", "--yaml"]).is_err());
+    assert!(parse(vec!["--yaml"]).is_err());
+
+    let usage = cli.generate_usage();
+    assert!(usage.contains("app (--json | --yaml) (--json | --zfile <value>)\n"));
+}